@@ -8,6 +8,7 @@ use yew::prelude::*;
 use yew_chart::{
     axis::{Axis, Orientation, Scale},
     linear_axis_scale::LinearScale,
+    marker::Marker,
     series::{self, Data, Labeller, Series, Type},
     time_axis_scale::TimeScale,
 };
@@ -33,7 +34,6 @@ impl Component for App {
         let start_date = end_date.sub(Duration::milliseconds(4));
         let time = start_date..end_date;
 
-        let circle_labeller = Rc::from(series::circle_label()) as Rc<dyn Labeller>;
         let circle_text_labeller = Rc::from(series::circle_text_label("Label")) as Rc<dyn Labeller>;
 
         App {
@@ -42,17 +42,17 @@ impl Component for App {
                 (
                     start_date.add(Duration::milliseconds(1)).timestamp_millis(),
                     4.0,
-                    Some(circle_labeller.clone()),
+                    None,
                 ),
                 (
                     start_date.add(Duration::milliseconds(2)).timestamp_millis(),
                     3.0,
-                    Some(circle_labeller.clone()),
+                    None,
                 ),
                 (
                     start_date.add(Duration::milliseconds(3)).timestamp_millis(),
                     2.0,
-                    Some(circle_labeller),
+                    None,
                 ),
                 (
                     start_date.add(Duration::milliseconds(4)).timestamp_millis(),
@@ -79,6 +79,8 @@ impl Component for App {
                 <Series<i64, f32>
                     series_type={Type::Scatter}
                     name="some-series"
+                    marker={Marker::Diamond}
+                    marker_size={6.0}
                     data={self.data_set.clone()}
                     horizontal_scale={self.horizontal_axis_scale.clone()}
                     vertical_scale={self.vertical_axis_scale.clone()}
@@ -42,6 +42,19 @@ pub trait Scale {
     fn normalise(&self, value: Self::Scalar) -> NormalisedValue;
 }
 
+/// Specifies a scale that can also convert normalised values back into values within the scale,
+/// as required by interactions that report the data under the pointer
+pub trait InvertibleScale: Scale {
+    /// The inverse of [normalise](Scale::normalise), converting a normalised value back into a
+    /// value within the axis scale
+    ///
+    /// For example, for a linear scale between 50 and 100:
+    /// - denormalise(0)   -> 50
+    /// - denormalise(0.5) -> 75
+    /// - denormalise(1)   -> 100
+    fn denormalise(&self, value: NormalisedValue) -> Self::Scalar;
+}
+
 /// An axis tick, specifying a label to be displayed at some normalised
 /// position along the axis
 #[derive(Debug, PartialEq)]
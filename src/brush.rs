@@ -0,0 +1,431 @@
+/// A Brush is an interaction overlay that lets a range of a chart be selected by dragging over
+/// its plot area. The selection may span the x axis only, or both axes. Once made, the selection
+/// may be moved by dragging it, or resized by dragging its handles. Clicking outside of the
+/// selection clears it.
+///
+/// Each selection is reported in data units through a callback once the pointer is released,
+/// using the inverse mapping of the scales, and so the scales must be an [InvertibleScale]. The
+/// scales are expected to be the same ones provided to the chart's
+/// [Series](crate::series::Series). A series takes a [Scale](crate::axis::Scale) instead, and so
+/// each scale is best held as its concrete type, such as an `Rc<TimeScale>`, with a clone cast to
+/// `Rc<dyn InvertibleScale<Scalar = _>>` for the brush. Deriving an `Rc<dyn Scale>` from an
+/// `Rc<dyn InvertibleScale>` would require trait upcasting, only available from Rust 1.86.
+///
+/// The overlay is drawn as a transparent rectangle over the plot area and so it should be declared
+/// after any series so that it receives pointer events.
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * brush - the brush as a whole
+/// * overlay - the transparent rectangle receiving pointer events
+/// * selection - the selected region
+/// * handle - a handle for resizing the selection, along with left, right, top or bottom
+use std::{marker::PhantomData, ops::Range, rc::Rc};
+
+use web_sys::Element;
+use yew::prelude::*;
+
+use crate::{
+    axis::{InvertibleScale, NormalisedValue},
+    series::Scalar,
+};
+
+// Selections smaller than this fraction of the plot area are treated as clicks
+const MIN_FRACTION: f32 = 0.002;
+
+/// Describes the axes that a brush selects over
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mode {
+    /// Selects a range of x values spanning the full height of the plot area
+    X,
+    /// Selects a range of both x and y values
+    XY,
+}
+
+/// A selection in data units
+#[derive(Clone, Debug, PartialEq)]
+pub struct Selection<A, B> {
+    /// The range of x values selected
+    pub x: Range<A>,
+    /// The range of y values selected, or None if only x values are selected
+    pub y: Option<Range<B>>,
+}
+
+/// A selected region as ordered fractions of the plot area's width and height, measured from the
+/// top left.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Extent {
+    x: (f32, f32),
+    y: (f32, f32),
+}
+
+/// An edge of a selection that may be dragged to resize it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Edge {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl Edge {
+    fn class(&self) -> &'static str {
+        match self {
+            Edge::Left => "left",
+            Edge::Right => "right",
+            Edge::Top => "top",
+            Edge::Bottom => "bottom",
+        }
+    }
+
+    fn cursor(&self) -> &'static str {
+        match self {
+            Edge::Left | Edge::Right => "ew-resize",
+            Edge::Top | Edge::Bottom => "ns-resize",
+        }
+    }
+}
+
+/// Describes a drag in progress, along with the state at its start
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Drag {
+    Create((f32, f32)),
+    Move((f32, f32), Extent),
+    Resize(Edge, Extent),
+}
+
+fn order(a: f32, b: f32) -> (f32, f32) {
+    (a.min(b), a.max(b))
+}
+
+/// Determines the extent resulting from a drag given the pointer's current position.
+fn drag(drag: &Drag, pointer: (f32, f32), mode: Mode) -> Extent {
+    let full = (0.0, 1.0);
+    match *drag {
+        Drag::Create(origin) => Extent {
+            x: order(origin.0, pointer.0),
+            y: match mode {
+                Mode::X => full,
+                Mode::XY => order(origin.1, pointer.1),
+            },
+        },
+        Drag::Move(origin, extent) => {
+            let dx = (pointer.0 - origin.0).clamp(-extent.x.0, 1.0 - extent.x.1);
+            let dy = (pointer.1 - origin.1).clamp(-extent.y.0, 1.0 - extent.y.1);
+            Extent {
+                x: (extent.x.0 + dx, extent.x.1 + dx),
+                y: (extent.y.0 + dy, extent.y.1 + dy),
+            }
+        }
+        Drag::Resize(edge, extent) => match edge {
+            Edge::Left => Extent {
+                x: order(pointer.0, extent.x.1),
+                ..extent
+            },
+            Edge::Right => Extent {
+                x: order(extent.x.0, pointer.0),
+                ..extent
+            },
+            Edge::Top => Extent {
+                y: order(pointer.1, extent.y.1),
+                ..extent
+            },
+            Edge::Bottom => Extent {
+                y: order(extent.y.0, pointer.1),
+                ..extent
+            },
+        },
+    }
+}
+
+pub enum Msg {
+    Start(Option<Edge>, bool, (f32, f32)),
+    Move((f32, f32)),
+    End,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    /// The SVG size of the handles used for resizing
+    #[prop_or(6.0)]
+    pub handle_size: f32,
+    /// The SVG height of the plot area
+    pub height: f32,
+    /// The scaling factor for data along the x axis
+    pub horizontal_scale: Rc<dyn InvertibleScale<Scalar = A>>,
+    /// The axes that the brush selects over
+    #[prop_or(Mode::X)]
+    pub mode: Mode,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    /// A callback to receive the selection when it is made or changed, or None when it is cleared
+    pub onbrush: Callback<Option<Selection<A, B>>>,
+    /// The scaling factor for data along the y axis
+    pub vertical_scale: Rc<dyn InvertibleScale<Scalar = B>>,
+    /// The SVG width of the plot area
+    pub width: f32,
+    /// The start position
+    pub x: f32,
+    /// The start position
+    pub y: f32,
+}
+
+impl<A, B> PartialEq for Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.handle_size == other.handle_size
+            && self.height == other.height
+            && self.mode == other.mode
+            && self.name == other.name
+            && self.onbrush == other.onbrush
+            && self.width == other.width
+            && self.x == other.x
+            && self.y == other.y
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.horizontal_scale as *const _ as *const u8,
+                &*other.horizontal_scale as *const _ as *const u8,
+            )
+            && std::ptr::eq(
+                &*self.vertical_scale as *const _ as *const u8,
+                &*other.vertical_scale as *const _ as *const u8,
+            )
+    }
+}
+
+pub struct Brush<A, B> {
+    drag: Option<Drag>,
+    extent: Option<Extent>,
+    overlay: NodeRef,
+    phantom: PhantomData<(A, B)>,
+}
+
+impl<A: 'static, B: 'static> Component for Brush<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    type Message = Msg;
+
+    type Properties = Props<A, B>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Brush {
+            drag: None,
+            extent: None,
+            overlay: NodeRef::default(),
+            phantom: PhantomData,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let p = ctx.props();
+        match msg {
+            Msg::Start(edge, on_selection, pointer) => {
+                self.drag = Some(match (edge, self.extent) {
+                    (Some(edge), Some(extent)) => Drag::Resize(edge, extent),
+                    (None, Some(extent)) if on_selection => Drag::Move(pointer, extent),
+                    _ => {
+                        self.extent = None;
+                        Drag::Create(pointer)
+                    }
+                });
+                true
+            }
+            Msg::Move(pointer) => match &self.drag {
+                Some(d) => {
+                    self.extent = Some(drag(d, pointer, p.mode));
+                    true
+                }
+                None => false,
+            },
+            Msg::End => {
+                if self.drag.take().is_none() {
+                    return false;
+                }
+                self.extent = self.extent.filter(|e| {
+                    e.x.1 - e.x.0 >= MIN_FRACTION
+                        && (p.mode == Mode::X || e.y.1 - e.y.0 >= MIN_FRACTION)
+                });
+                p.onbrush.emit(self.extent.map(|e| Selection {
+                    x: p.horizontal_scale.denormalise(NormalisedValue(e.x.0))
+                        ..p.horizontal_scale.denormalise(NormalisedValue(e.x.1)),
+                    y: (p.mode == Mode::XY).then(|| {
+                        p.vertical_scale.denormalise(NormalisedValue(1.0 - e.y.1))
+                            ..p.vertical_scale.denormalise(NormalisedValue(1.0 - e.y.0))
+                    }),
+                }));
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let p = ctx.props();
+
+        let overlay = self.overlay.clone();
+        let pointer = move |e: &PointerEvent| {
+            overlay.cast::<Element>().map(|o| {
+                let rect = o.get_bounding_client_rect();
+                let fraction = |client: i32, start: f64, size: f64| {
+                    if size > 0.0 {
+                        (((client as f64 - start) / size) as f32).clamp(0.0, 1.0)
+                    } else {
+                        0.0
+                    }
+                };
+                (
+                    fraction(e.client_x(), rect.left(), rect.width()),
+                    fraction(e.client_y(), rect.top(), rect.height()),
+                )
+            })
+        };
+        let onpointerdown = |edge: Option<Edge>, on_selection: bool| {
+            let pointer = pointer.clone();
+            ctx.link().batch_callback(move |e: PointerEvent| {
+                let _ = e
+                    .target_unchecked_into::<Element>()
+                    .set_pointer_capture(e.pointer_id());
+                pointer(&e).map(|p| Msg::Start(edge, on_selection, p))
+            })
+        };
+        let onpointermove = {
+            let pointer = pointer.clone();
+            ctx.link()
+                .batch_callback(move |e: PointerEvent| pointer(&e).map(Msg::Move))
+        };
+        let onpointerup = ctx.link().callback(|_: PointerEvent| Msg::End);
+
+        let to_svg = |e: &Extent| {
+            (
+                p.x + e.x.0 * p.width,
+                p.y + e.y.0 * p.height,
+                (e.x.1 - e.x.0) * p.width,
+                (e.y.1 - e.y.0) * p.height,
+            )
+        };
+
+        let selection = self.extent.map(|extent| {
+            let (x, y, width, height) = to_svg(&extent);
+            let edges = match p.mode {
+                Mode::X => vec![Edge::Left, Edge::Right],
+                Mode::XY => vec![Edge::Left, Edge::Right, Edge::Top, Edge::Bottom],
+            };
+            let half = p.handle_size * 0.5;
+            let handles = edges.into_iter().map(|edge| {
+                let (hx, hy, hw, hh) = match edge {
+                    Edge::Left => (x - half, y, p.handle_size, height),
+                    Edge::Right => (x + width - half, y, p.handle_size, height),
+                    Edge::Top => (x, y - half, width, p.handle_size),
+                    Edge::Bottom => (x, y + height - half, width, p.handle_size),
+                };
+                html! {
+                    <rect x={hx.to_string()} y={hy.to_string()} width={hw.to_string()} height={hh.to_string()}
+                        class={classes!("handle", edge.class())} cursor={edge.cursor()}
+                        onpointerdown={onpointerdown(Some(edge), true)}
+                        onpointermove={onpointermove.clone()} onpointerup={onpointerup.clone()} />
+                }
+            });
+            html! {
+                <>
+                <rect x={x.to_string()} y={y.to_string()} width={width.to_string()} height={height.to_string()}
+                    class="selection" cursor="move"
+                    onpointerdown={onpointerdown(None, true)}
+                    onpointermove={onpointermove.clone()} onpointerup={onpointerup.clone()} />
+                { for handles }
+                </>
+            }
+        });
+
+        html! {
+            <g class={classes!("brush", &p.name)} style="touch-action: none">
+                <rect ref={self.overlay.clone()} x={p.x.to_string()} y={p.y.to_string()} width={p.width.to_string()} height={p.height.to_string()}
+                    fill="transparent" class="overlay" cursor="crosshair"
+                    onpointerdown={onpointerdown(None, false)}
+                    onpointermove={onpointermove.clone()} onpointerup={onpointerup.clone()} />
+                { for selection }
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXTENT: Extent = Extent {
+        x: (0.2, 0.4),
+        y: (0.0, 1.0),
+    };
+
+    #[test]
+    fn test_drag_create() {
+        assert_eq!(
+            drag(&Drag::Create((0.5, 0.5)), (0.25, 0.75), Mode::X),
+            Extent {
+                x: (0.25, 0.5),
+                y: (0.0, 1.0)
+            }
+        );
+        assert_eq!(
+            drag(&Drag::Create((0.5, 0.5)), (0.25, 0.75), Mode::XY),
+            Extent {
+                x: (0.25, 0.5),
+                y: (0.5, 0.75)
+            }
+        );
+    }
+
+    #[test]
+    fn test_drag_move() {
+        assert_eq!(
+            drag(&Drag::Move((0.5, 0.5), EXTENT), (0.75, 0.75), Mode::X),
+            Extent {
+                x: (0.45, 0.65),
+                y: (0.0, 1.0)
+            }
+        );
+        assert_eq!(
+            drag(&Drag::Move((0.5, 0.5), EXTENT), (0.0, 0.5), Mode::X),
+            Extent {
+                x: (0.0, 0.2),
+                y: (0.0, 1.0)
+            }
+        );
+    }
+
+    #[test]
+    fn test_drag_resize() {
+        assert_eq!(
+            drag(&Drag::Resize(Edge::Left, EXTENT), (0.1, 0.5), Mode::X),
+            Extent {
+                x: (0.1, 0.4),
+                ..EXTENT
+            }
+        );
+        assert_eq!(
+            drag(&Drag::Resize(Edge::Left, EXTENT), (0.5, 0.5), Mode::X),
+            Extent {
+                x: (0.4, 0.5),
+                ..EXTENT
+            }
+        );
+        assert_eq!(
+            drag(&Drag::Resize(Edge::Bottom, EXTENT), (0.5, 0.5), Mode::XY),
+            Extent {
+                y: (0.0, 0.5),
+                ..EXTENT
+            }
+        );
+    }
+}
@@ -0,0 +1,240 @@
+/// A BubbleSeries represents data having a third dimension that is conveyed by the size of a
+/// marker at each x/y position. The size value is normalised by a size scale, typically a
+/// [LinearScale](crate::linear_axis_scale::LinearScale) starting at zero, and each marker's area
+/// is then made proportional to the normalised value, as is conventional for bubble charts.
+///
+/// Larger bubbles are drawn before smaller ones so that small bubbles remain visible.
+///
+/// A name is associated with the series to facilitate styling.
+///
+/// The following styling properties are available:
+///
+/// * series - the series as a whole
+/// * bubble - each bubble, along with the marker's classes
+use std::{fmt::Display, marker::PhantomData, rc::Rc};
+
+use yew::prelude::*;
+
+#[cfg(feature = "custom-tooltip")]
+use crate::series::TooltipCallback;
+use crate::{
+    axis::{NormalisedValue, Scale},
+    marker::Marker,
+    series::Scalar,
+};
+
+/// Describes a bubble series with each point having an x, y and size
+pub type Data<A, B> = Vec<(A, B, f32)>;
+
+/// Describes a closure that takes data values (x, y, size) and produces tooltip strings for
+/// each datapoint.
+pub trait Tooltipper<A: Scalar, B: Scalar>: Fn(A, B, f32) -> String {}
+
+impl<A: Scalar, B: Scalar, T: Fn(A, B, f32) -> String> Tooltipper<A, B> for T {}
+
+/// Basic tooltip that outputs the x, y and size values
+pub fn xyz_tooltip<A: Scalar + Display, B: Scalar + Display>() -> impl Tooltipper<A, B> {
+    |x: A, y: B, size: f32| format!("{x}, {y}: {size}")
+}
+
+/// The order in which to draw data such that the largest sizes are drawn first.
+/// The radius of a bubble given its normalised size, such that its area is proportional to the
+/// size. Negative sizes are regarded as zero.
+fn radius(size: NormalisedValue, max_radius: f32) -> f32 {
+    size.0.max(0.0).sqrt() * max_radius
+}
+
+fn draw_order<A, B>(data: &Data<A, B>) -> Vec<usize> {
+    let mut order = (0..data.len()).collect::<Vec<_>>();
+    order.sort_by(|l, r| {
+        data[*r]
+            .2
+            .partial_cmp(&data[*l].2)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    order
+}
+
+#[derive(Properties, Clone)]
+pub struct Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    /// A vector of data points that represents the series
+    pub data: Rc<Data<A, B>>,
+    /// The SVG height of the series
+    pub height: f32,
+    /// The scaling factor for data along the x axis
+    pub horizontal_scale: Rc<dyn Scale<Scalar = A>>,
+    /// The marker to draw for each bubble
+    #[prop_or(Marker::Circle)]
+    pub marker: Marker,
+    /// The SVG radius of a bubble at the maximum of the size scale
+    pub max_radius: f32,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    #[cfg(feature = "custom-tooltip")]
+    /// A callback to receive mouseover events along with tooltipper function text results. Requires
+    /// the custom-tooltip feature.
+    #[prop_or_else(|| Rc::new(Callback::noop()))]
+    pub onmouseover: Rc<TooltipCallback>,
+    /// The scaling factor for the size of each bubble, which is normalised into an area rather than
+    /// a radius
+    pub size_scale: Rc<dyn Scale<Scalar = f32>>,
+    /// An optional function that renders a string to be used for tooltips
+    #[prop_or_default]
+    pub tooltipper: Option<Rc<dyn Tooltipper<A, B>>>,
+    /// The scaling factor for data along the y axis
+    pub vertical_scale: Rc<dyn Scale<Scalar = B>>,
+    /// The SVG width of the series
+    pub width: f32,
+    /// The start position
+    pub x: f32,
+    /// The start position
+    pub y: f32,
+}
+
+impl<A, B> Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    #[cfg(feature = "custom-tooltip")]
+    fn is_onmouseover_eq(&self, other: &Self) -> bool {
+        self.onmouseover == other.onmouseover
+    }
+    #[cfg(not(feature = "custom-tooltip"))]
+    fn is_onmouseover_eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<A, B> PartialEq for Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.data, &other.data)
+            && self.height == other.height
+            && self.marker == other.marker
+            && self.max_radius == other.max_radius
+            && self.name == other.name
+            && self.is_onmouseover_eq(other)
+            && match (self.tooltipper.as_ref(), other.tooltipper.as_ref()) {
+                (Some(left), Some(right)) => std::ptr::eq(left as *const _ as *const u8, right as *const _ as *const u8),
+                _=> false
+            }
+            && self.width == other.width
+            && self.x == other.x
+            && self.y == other.y
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.horizontal_scale as *const _ as *const u8,
+                &*other.horizontal_scale as *const _ as *const u8,
+            )
+            && std::ptr::eq(
+                &*self.size_scale as *const _ as *const u8,
+                &*other.size_scale as *const _ as *const u8,
+            )
+            && std::ptr::eq(
+                &*self.vertical_scale as *const _ as *const u8,
+                &*other.vertical_scale as *const _ as *const u8,
+            )
+    }
+}
+
+pub struct BubbleSeries<A, B> {
+    phantom: PhantomData<(A, B)>,
+}
+
+impl<A: 'static, B: 'static> Component for BubbleSeries<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    type Message = ();
+
+    type Properties = Props<A, B>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        BubbleSeries {
+            phantom: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        #[cfg(feature = "custom-tooltip")]
+        fn onmouseover(cb: &Rc<TooltipCallback>, title: String) -> impl Fn(MouseEvent) {
+            let cb = Rc::clone(cb);
+            move |e| {
+                (*cb).emit((e, title.clone()));
+            }
+        }
+
+        let p = ctx.props();
+
+        let x_bounds = -0.1..=p.width + 0.1;
+        let y_bounds = -0.1..=p.height + 0.1;
+
+        let bubbles = draw_order(&p.data).into_iter().filter_map(|i| {
+            let (data_x, data_y, size) = p.data[i];
+            let x = p.horizontal_scale.normalise(data_x).0 * p.width;
+            let y = p.vertical_scale.normalise(data_y).0 * p.height;
+            if !(x_bounds.contains(&x) && y_bounds.contains(&y)) {
+                return None;
+            }
+            let x = x + p.x;
+            let y = p.height - y + p.y;
+            let radius = radius(p.size_scale.normalise(size), p.max_radius);
+
+            let marker = p.marker.render(x, y, radius * 2.0, None);
+            let title = p.tooltipper.as_ref().map(|tt| tt(data_x, data_y, size));
+
+            #[cfg(feature = "custom-tooltip")]
+            let html = html! {
+                <g class="bubble" onmouseover={onmouseover(&p.onmouseover, title.unwrap_or_default())}>
+                    {marker}
+                </g>
+            };
+            #[cfg(not(feature = "custom-tooltip"))]
+            let html = html! {
+                <g class="bubble">
+                    if let Some(t) = title {
+                        <title>{t}</title>
+                    }
+                    {marker}
+                </g>
+            };
+
+            Some(html)
+        });
+
+        html! {
+            <g class={classes!("series", &p.name)}>
+                { for bubbles }
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_draw_order() {
+        let data: Data<f32, f32> = vec![(0.0, 0.0, 1.0), (1.0, 1.0, 3.0), (2.0, 2.0, 2.0)];
+
+        assert_eq!(draw_order(&data), vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn test_radius() {
+        assert_eq!(radius(NormalisedValue(1.0), 10.0), 10.0);
+        assert_eq!(radius(NormalisedValue(0.25), 10.0), 5.0);
+        assert_eq!(radius(NormalisedValue(-1.0), 10.0), 0.0);
+    }
+}
@@ -0,0 +1,235 @@
+/// A Callout annotates a data point with text held in a box, and an arrow connecting the box to
+/// the point. The box is offset from the point and is kept within the plot area so that it is not
+/// clipped at the plot's edges. Text may span multiple lines by separating them with newlines.
+/// The scales are expected to be the same ones provided to the chart's
+/// [Series](crate::series::Series).
+///
+/// Callouts for points outside of the plot area are not drawn.
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * callout - the callout as a whole
+/// * connector - the line from the box to the point
+/// * arrow - the arrowhead at the point
+/// * box - the box behind the text
+/// * text - the text
+use std::{marker::PhantomData, rc::Rc};
+
+use web_sys::SvgGraphicsElement;
+use yew::prelude::*;
+
+use crate::{axis::Scale, series::Scalar};
+
+const ARROW_ANGLE: f32 = 0.4;
+const LINE_HEIGHT: &str = "1.2em";
+
+/// Positions a box of a given size centred on a point, moving it as little as possible so that
+/// it remains within the bounds (x, y, width, height). Returns the top left of the box.
+fn place(centre: (f32, f32), size: (f32, f32), bounds: (f32, f32, f32, f32)) -> (f32, f32) {
+    let (x, y, width, height) = bounds;
+    let left = (centre.0 - size.0 * 0.5).min(x + width - size.0).max(x);
+    let top = (centre.1 - size.1 * 0.5).min(y + height - size.1).max(y);
+    (left, top)
+}
+
+/// Determines the points of an arrowhead with its tip at a point, pointing away from another.
+fn arrowhead(tip: (f32, f32), from: (f32, f32), len: f32) -> Option<[(f32, f32); 3]> {
+    let (dx, dy) = (tip.0 - from.0, tip.1 - from.1);
+    if dx == 0.0 && dy == 0.0 {
+        return None;
+    }
+    let angle = dy.atan2(dx);
+    let barb = |a: f32| (tip.0 - len * a.cos(), tip.1 - len * a.sin());
+    Some([tip, barb(angle - ARROW_ANGLE), barb(angle + ARROW_ANGLE)])
+}
+
+pub enum Msg {
+    Measured(f32, f32),
+}
+
+#[derive(Properties, Clone)]
+pub struct Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    /// The length of the arrowhead
+    #[prop_or(6.0)]
+    pub arrow_len: f32,
+    /// The SVG height of the plot area
+    pub height: f32,
+    /// The scaling factor for data along the x axis
+    pub horizontal_scale: Rc<dyn Scale<Scalar = A>>,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    /// The SVG offset of the centre of the box from the point
+    #[prop_or((20.0, -20.0))]
+    pub offset: (f32, f32),
+    /// The space between the text and the edges of its box
+    #[prop_or(4.0)]
+    pub padding: f32,
+    /// The data point being annotated
+    pub point: (A, B),
+    /// The text of the callout, with lines separated by newlines
+    pub text: AttrValue,
+    /// The scaling factor for data along the y axis
+    pub vertical_scale: Rc<dyn Scale<Scalar = B>>,
+    /// The SVG width of the plot area
+    pub width: f32,
+    /// The start position
+    pub x: f32,
+    /// The start position
+    pub y: f32,
+}
+
+impl<A, B> PartialEq for Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.arrow_len == other.arrow_len
+            && self.height == other.height
+            && self.name == other.name
+            && self.offset == other.offset
+            && self.padding == other.padding
+            && self.point == other.point
+            && self.text == other.text
+            && self.width == other.width
+            && self.x == other.x
+            && self.y == other.y
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.horizontal_scale as *const _ as *const u8,
+                &*other.horizontal_scale as *const _ as *const u8,
+            )
+            && std::ptr::eq(
+                &*self.vertical_scale as *const _ as *const u8,
+                &*other.vertical_scale as *const _ as *const u8,
+            )
+    }
+}
+
+pub struct Callout<A, B> {
+    phantom: PhantomData<(A, B)>,
+    text: NodeRef,
+    text_size: (f32, f32),
+}
+
+impl<A: 'static, B: 'static> Component for Callout<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    type Message = Msg;
+
+    type Properties = Props<A, B>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Callout {
+            phantom: PhantomData,
+            text: NodeRef::default(),
+            text_size: (0.0, 0.0),
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Measured(width, height) => {
+                let changed = self.text_size != (width, height);
+                self.text_size = (width, height);
+                changed
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let p = ctx.props();
+
+        let (data_x, data_y) = p.point;
+        let norm_x = p.horizontal_scale.normalise(data_x).0;
+        let norm_y = p.vertical_scale.normalise(data_y).0;
+        if !(0.0..=1.0).contains(&norm_x) || !(0.0..=1.0).contains(&norm_y) {
+            return html!();
+        }
+        let point = (p.x + norm_x * p.width, p.y + p.height - norm_y * p.height);
+
+        let size = (
+            self.text_size.0 + p.padding * 2.0,
+            self.text_size.1 + p.padding * 2.0,
+        );
+        let (left, top) = place(
+            (point.0 + p.offset.0, point.1 + p.offset.1),
+            size,
+            (p.x, p.y, p.width, p.height),
+        );
+        let centre = (left + size.0 * 0.5, top + size.1 * 0.5);
+
+        let arrow = arrowhead(point, centre, p.arrow_len).map(|points| {
+            let points = points
+                .iter()
+                .map(|(x, y)| format!("{},{} ", x, y))
+                .collect::<Vec<_>>()
+                .concat();
+            html! {
+                <polygon points={points} class="arrow" />
+            }
+        });
+
+        let text_x = (left + p.padding).to_string();
+        let lines = p.text.split('\n').enumerate().map(|(i, line)| {
+            let dy = if i == 0 { "0" } else { LINE_HEIGHT };
+            html! {
+                <tspan x={text_x.clone()} dy={dy}>{line}</tspan>
+            }
+        });
+
+        html! {
+            <g class={classes!("callout", &p.name)}>
+                <line x1={centre.0.to_string()} y1={centre.1.to_string()} x2={point.0.to_string()} y2={point.1.to_string()} class="connector" />
+                {for arrow}
+                <rect x={left.to_string()} y={top.to_string()} width={size.0.to_string()} height={size.1.to_string()} class="box" />
+                <text ref={self.text.clone()} x={text_x.clone()} y={(top + p.padding).to_string()} dominant-baseline="hanging" class="text">
+                    { for lines }
+                </text>
+            </g>
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        if let Some(text) = self.text.cast::<SvgGraphicsElement>() {
+            if let Ok(bbox) = text.get_b_box() {
+                ctx.link()
+                    .send_message(Msg::Measured(bbox.width(), bbox.height()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_place() {
+        let bounds = (0.0, 0.0, 100.0, 50.0);
+
+        assert_eq!(place((50.0, 25.0), (20.0, 10.0), bounds), (40.0, 20.0));
+        assert_eq!(place((95.0, 2.0), (20.0, 10.0), bounds), (80.0, 0.0));
+        assert_eq!(place((5.0, 48.0), (20.0, 10.0), bounds), (0.0, 40.0));
+        assert_eq!(place((50.0, 25.0), (120.0, 10.0), bounds), (0.0, 20.0));
+    }
+
+    #[test]
+    fn test_arrowhead() {
+        let [tip, first, second] = arrowhead((10.0, 0.0), (0.0, 0.0), 5.0).unwrap();
+
+        assert_eq!(tip, (10.0, 0.0));
+        assert!(first.0 < 10.0 && first.1 > 0.0);
+        assert!(second.0 < 10.0 && second.1 < 0.0);
+        assert_eq!(arrowhead((1.0, 1.0), (1.0, 1.0), 5.0), None);
+    }
+}
@@ -0,0 +1,219 @@
+/// A ColourBar is a legend for a [ColourScale]. It renders the colours of the scale as a
+/// gradient filled rectangle alongside an [Axis] describing the values that they represent.
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * colour-bar - the colour bar as a whole
+/// * bar - the gradient filled rectangle
+///
+/// The axis may be styled as per any other [Axis].
+use std::{
+    marker::PhantomData,
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use yew::prelude::*;
+
+use crate::{
+    axis::{Axis, NormalisedValue, Orientation, Scale},
+    colour_scale::{ColourScale, ColourStop},
+    series::Scalar,
+};
+
+#[derive(Properties, Clone)]
+pub struct Props<S: Scalar> {
+    /// A name given to the colour bar that will be used for CSS classes
+    pub name: AttrValue,
+    /// The colours to be rendered
+    pub colour_scale: Rc<dyn ColourScale<Scalar = S>>,
+    /// The scale used for the ticks of the colour bar's axis. This is normally the
+    /// same scale as the one used to construct the colour scale.
+    pub scale: Rc<dyn Scale<Scalar = S>>,
+    /// Which side of the bar the axis is drawn on. Left and right produce a
+    /// vertical bar, top and bottom a horizontal one.
+    pub orientation: Orientation,
+    /// The start position
+    pub x: f32,
+    /// The start position
+    pub y: f32,
+    /// The SVG width of the bar
+    pub width: f32,
+    /// The SVG height of the bar
+    pub height: f32,
+    /// The length of ticks
+    pub tick_len: f32,
+    /// Any title to be drawn and associated with the axis
+    #[prop_or_default]
+    pub title: Option<AttrValue>,
+}
+
+impl<S: Scalar> PartialEq for Props<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.orientation == other.orientation
+            && self.x == other.x
+            && self.y == other.y
+            && self.width == other.width
+            && self.height == other.height
+            && self.tick_len == other.tick_len
+            && self.title == other.title
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.colour_scale as *const _ as *const u8,
+                &*other.colour_scale as *const _ as *const u8,
+            )
+            && std::ptr::eq(
+                &*self.scale as *const _ as *const u8,
+                &*other.scale as *const _ as *const u8,
+            )
+    }
+}
+
+/// The offset and colour attributes of each stop of a gradient.
+fn gradient_stops(stops: &[ColourStop]) -> Vec<(String, String)> {
+    stops
+        .iter()
+        .map(
+            |ColourStop {
+                 location: NormalisedValue(location),
+                 colour,
+             }| (location.to_string(), colour.to_string()),
+        )
+        .collect()
+}
+
+// The direction of a gradient and the position of an axis
+#[derive(Debug, PartialEq)]
+struct Layout {
+    gradient: (&'static str, &'static str, &'static str, &'static str),
+    axis: (f32, f32, f32),
+}
+
+/// Lays out the gradient and axis of a bar given as x, y, width and height. Gradients run from
+/// the minimum of the scale at the bottom or left to the maximum at the top or right, matching
+/// the direction of an axis. The axis is given as its start position and end along its length.
+fn layout(orientation: &Orientation, (x, y, width, height): (f32, f32, f32, f32)) -> Layout {
+    let gradient = match orientation {
+        Orientation::Left | Orientation::Right => ("0", "1", "0", "0"),
+        Orientation::Bottom | Orientation::Top => ("0", "0", "1", "0"),
+    };
+    let axis = match orientation {
+        Orientation::Left => (x, y, y + height),
+        Orientation::Right => (x + width, y, y + height),
+        Orientation::Bottom => (x, y + height, x + width),
+        Orientation::Top => (x, y, x + width),
+    };
+    Layout { gradient, axis }
+}
+
+// SVG ids are global to the document and so each colour bar is given its own gradient id
+static NEXT_GRADIENT_ID: AtomicUsize = AtomicUsize::new(0);
+
+pub struct ColourBar<S: Scalar> {
+    gradient_id: String,
+    phantom: PhantomData<S>,
+}
+
+impl<S: Scalar + 'static> Component for ColourBar<S> {
+    type Message = ();
+
+    type Properties = Props<S>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        ColourBar {
+            gradient_id: format!(
+                "colour-bar-gradient-{}",
+                NEXT_GRADIENT_ID.fetch_add(1, Ordering::Relaxed)
+            ),
+            phantom: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let p = ctx.props();
+
+        let gradient_id = &self.gradient_id;
+        let Layout {
+            gradient: (gx1, gy1, gx2, gy2),
+            axis: (x1, y1, xy2),
+        } = layout(&p.orientation, (p.x, p.y, p.width, p.height));
+
+        html! {
+            <g class={classes!("colour-bar", &p.name)}>
+                <defs>
+                    <linearGradient id={gradient_id.clone()} x1={gx1} y1={gy1} x2={gx2} y2={gy2}>
+                        { for gradient_stops(&p.colour_scale.stops()).into_iter().map(|(offset, colour)| html! {
+                            <stop offset={offset} stop-color={colour} />
+                        }) }
+                    </linearGradient>
+                </defs>
+                <rect
+                    x={p.x.to_string()} y={p.y.to_string()}
+                    width={p.width.to_string()} height={p.height.to_string()}
+                    fill={format!("url(#{})", gradient_id)}
+                    class="bar" />
+                <Axis<S>
+                    name={p.name.clone()}
+                    orientation={p.orientation.clone()}
+                    scale={Rc::clone(&p.scale)}
+                    x1={x1} y1={y1} xy2={xy2}
+                    tick_len={p.tick_len}
+                    title={p.title.clone()} />
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colour_scale::Colour;
+
+    #[test]
+    fn test_gradient_stops() {
+        let stops = vec![
+            ColourStop {
+                location: NormalisedValue(0.0),
+                colour: Colour::hex(0x440154),
+            },
+            ColourStop {
+                location: NormalisedValue(0.5),
+                colour: Colour::hex(0xfde725),
+            },
+        ];
+
+        assert_eq!(
+            gradient_stops(&stops),
+            vec![
+                ("0".to_string(), "#440154".to_string()),
+                ("0.5".to_string(), "#fde725".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_layout() {
+        let bounds = (10.0, 20.0, 30.0, 40.0);
+
+        assert_eq!(
+            layout(&Orientation::Left, bounds),
+            Layout {
+                gradient: ("0", "1", "0", "0"),
+                axis: (10.0, 20.0, 60.0),
+            }
+        );
+        assert_eq!(layout(&Orientation::Right, bounds).axis, (40.0, 20.0, 60.0));
+        assert_eq!(
+            layout(&Orientation::Bottom, bounds),
+            Layout {
+                gradient: ("0", "0", "1", "0"),
+                axis: (10.0, 60.0, 40.0),
+            }
+        );
+        assert_eq!(layout(&Orientation::Top, bounds).axis, (10.0, 20.0, 40.0));
+    }
+}
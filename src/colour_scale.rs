@@ -0,0 +1,515 @@
+/// A ColourScale maps data values to colours so that a value can be conveyed by the fill or
+/// stroke of an SVG element rather than by its position. Colour scales are built on top of a
+/// positional [Scale], which is used to normalise values before they are mapped through an
+/// [Interpolator].
+///
+/// Sequential, diverging, quantized and threshold scales are provided along with a number of
+/// perceptually uniform palettes such as viridis.
+use std::{fmt, rc::Rc};
+
+use crate::{
+    axis::{NormalisedValue, Scale},
+    series::Scalar,
+};
+
+/// An RGB colour that renders as an SVG colour string e.g. `#440154`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Colour {
+    /// Create a colour from its red, green and blue components
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Colour {
+        Colour { r, g, b }
+    }
+
+    /// Create a colour from a 24 bit hex value e.g. 0x440154
+    pub const fn hex(value: u32) -> Colour {
+        Colour {
+            r: (value >> 16) as u8,
+            g: (value >> 8) as u8,
+            b: value as u8,
+        }
+    }
+
+    /// Linearly interpolate between this colour and another, where t
+    /// is expected to be between 0 and 1
+    pub fn mix(&self, other: &Colour, t: f32) -> Colour {
+        fn channel(from: u8, to: u8, t: f32) -> u8 {
+            (from as f32 + (to as f32 - from as f32) * t).round() as u8
+        }
+        Colour {
+            r: channel(self.r, other.r, t),
+            g: channel(self.g, other.g, t),
+            b: channel(self.b, other.b, t),
+        }
+    }
+}
+
+impl fmt::Display for Colour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Describes a closure that takes a value between 0 and 1 and produces a colour
+pub trait Interpolator: Fn(f32) -> Colour {}
+
+impl<T: Fn(f32) -> Colour> Interpolator for T {}
+
+/// An interpolator that blends evenly between a list of colours
+pub fn palette(colours: Vec<Colour>) -> impl Interpolator {
+    move |t: f32| {
+        let last = colours.len().saturating_sub(1);
+        let position = t.clamp(0.0, 1.0) * last as f32;
+        let i = (position.floor() as usize).min(last.saturating_sub(1));
+        match (colours.get(i), colours.get(i + 1)) {
+            (Some(from), Some(to)) => from.mix(to, position - i as f32),
+            (Some(from), None) => *from,
+            _ => Colour::rgb(0, 0, 0),
+        }
+    }
+}
+
+const VIRIDIS: [u32; 10] = [
+    0x440154, 0x482878, 0x3e4989, 0x31688e, 0x26828e, 0x1f9e89, 0x35b779, 0x6ece58, 0xb5de2b,
+    0xfde725,
+];
+
+const MAGMA: [u32; 10] = [
+    0x000004, 0x180f3d, 0x440f76, 0x721f81, 0x9e2f7f, 0xcd4071, 0xf1605d, 0xfd9668, 0xfeca8d,
+    0xfcfdbf,
+];
+
+const INFERNO: [u32; 10] = [
+    0x000004, 0x1b0c41, 0x4a0c6b, 0x781c6d, 0xa52c60, 0xcf4446, 0xed6925, 0xfb9b06, 0xf7d13d,
+    0xfcffa4,
+];
+
+const PLASMA: [u32; 10] = [
+    0x0d0887, 0x46039f, 0x7201a8, 0x9c179e, 0xbd3786, 0xd8576b, 0xed7953, 0xfb9f3a, 0xfdca26,
+    0xf0f921,
+];
+
+const CIVIDIS: [u32; 10] = [
+    0x00224e, 0x123570, 0x3b496c, 0x575d6d, 0x707173, 0x8a8779, 0xa69d75, 0xc4b56c, 0xe4cf5b,
+    0xfee838,
+];
+
+const RED_BLUE: [u32; 11] = [
+    0x67001f, 0xb2182b, 0xd6604d, 0xf4a582, 0xfddbc7, 0xf7f7f7, 0xd1e5f0, 0x92c5de, 0x4393c3,
+    0x2166ac, 0x053061,
+];
+
+const BROWN_BLUE_GREEN: [u32; 11] = [
+    0x543005, 0x8c510a, 0xbf812d, 0xdfc27d, 0xf6e8c3, 0xf5f5f5, 0xc7eae5, 0x80cdc1, 0x35978f,
+    0x01665e, 0x003c30,
+];
+
+const RED_YELLOW_BLUE: [u32; 11] = [
+    0xa50026, 0xd73027, 0xf46d43, 0xfdae61, 0xfee090, 0xffffbf, 0xe0f3f8, 0xabd9e9, 0x74add1,
+    0x4575b4, 0x313695,
+];
+
+fn hex_palette(colours: &[u32]) -> impl Interpolator {
+    palette(colours.iter().map(|c| Colour::hex(*c)).collect())
+}
+
+/// The viridis sequential palette, from dark purple to yellow
+pub fn viridis() -> impl Interpolator {
+    hex_palette(&VIRIDIS)
+}
+
+/// The magma sequential palette, from black to pale yellow via purple
+pub fn magma() -> impl Interpolator {
+    hex_palette(&MAGMA)
+}
+
+/// The inferno sequential palette, from black to pale yellow via red
+pub fn inferno() -> impl Interpolator {
+    hex_palette(&INFERNO)
+}
+
+/// The plasma sequential palette, from dark blue to yellow
+pub fn plasma() -> impl Interpolator {
+    hex_palette(&PLASMA)
+}
+
+/// The cividis sequential palette, designed for colour vision deficiencies
+pub fn cividis() -> impl Interpolator {
+    hex_palette(&CIVIDIS)
+}
+
+/// The red-blue diverging palette with a neutral midpoint
+pub fn red_blue() -> impl Interpolator {
+    hex_palette(&RED_BLUE)
+}
+
+/// The brown-blue-green diverging palette with a neutral midpoint
+pub fn brown_blue_green() -> impl Interpolator {
+    hex_palette(&BROWN_BLUE_GREEN)
+}
+
+/// The red-yellow-blue diverging palette with a neutral midpoint
+pub fn red_yellow_blue() -> impl Interpolator {
+    hex_palette(&RED_YELLOW_BLUE)
+}
+
+/// A colour at some normalised position along a colour scale
+#[derive(Debug, PartialEq)]
+pub struct ColourStop {
+    /// normalised location between zero and one along the scale
+    pub location: NormalisedValue,
+
+    /// The colour to be rendered at the location
+    pub colour: Colour,
+}
+
+/// Specifies a generic mapping of values to colours
+pub trait ColourScale {
+    type Scalar: Scalar;
+
+    /// Maps a value to a colour
+    fn colour(&self, value: Self::Scalar) -> Colour;
+
+    /// Provides the list of [stops](ColourStop) that describe the scale from its
+    /// minimum to its maximum, typically to render a gradient within a legend
+    fn stops(&self) -> Vec<ColourStop>;
+}
+
+const GRADIENT_STOPS: usize = 16;
+
+fn gradient_stops(colour: impl Fn(f32) -> Colour) -> Vec<ColourStop> {
+    (0..=GRADIENT_STOPS)
+        .map(|i| {
+            let location = i as f32 / GRADIENT_STOPS as f32;
+            ColourStop {
+                location: NormalisedValue(location),
+                colour: colour(location),
+            }
+        })
+        .collect()
+}
+
+/// Maps values continuously from the minimum to the maximum of a scale
+#[derive(Clone)]
+pub struct SequentialColourScale<S: Scalar> {
+    scale: Rc<dyn Scale<Scalar = S>>,
+    interpolator: Rc<dyn Interpolator>,
+}
+
+impl<S: Scalar> SequentialColourScale<S> {
+    /// Create a new colour scale given a scale to normalise values and a palette
+    pub fn new(
+        scale: Rc<dyn Scale<Scalar = S>>,
+        interpolator: Rc<dyn Interpolator>,
+    ) -> SequentialColourScale<S> {
+        SequentialColourScale {
+            scale,
+            interpolator,
+        }
+    }
+}
+
+impl<S: Scalar> ColourScale for SequentialColourScale<S> {
+    type Scalar = S;
+
+    fn colour(&self, value: Self::Scalar) -> Colour {
+        (self.interpolator)(self.scale.normalise(value).0)
+    }
+
+    fn stops(&self) -> Vec<ColourStop> {
+        gradient_stops(|location| (self.interpolator)(location))
+    }
+}
+
+/// Maps values either side of a midpoint to either half of a palette, such
+/// that the midpoint is always rendered with the palette's central colour
+#[derive(Clone)]
+pub struct DivergingColourScale<S: Scalar> {
+    scale: Rc<dyn Scale<Scalar = S>>,
+    midpoint: f32,
+    interpolator: Rc<dyn Interpolator>,
+}
+
+impl<S: Scalar> DivergingColourScale<S> {
+    /// Create a new colour scale given a scale to normalise values, the value
+    /// at which the palette diverges and a palette
+    pub fn new(
+        scale: Rc<dyn Scale<Scalar = S>>,
+        midpoint: S,
+        interpolator: Rc<dyn Interpolator>,
+    ) -> DivergingColourScale<S> {
+        let midpoint = scale.normalise(midpoint).0;
+        DivergingColourScale {
+            scale,
+            midpoint,
+            interpolator,
+        }
+    }
+
+    fn diverge(&self, location: f32) -> f32 {
+        if location < self.midpoint {
+            if self.midpoint > 0.0 {
+                0.5 * location / self.midpoint
+            } else {
+                0.0
+            }
+        } else if self.midpoint < 1.0 {
+            0.5 + 0.5 * (location - self.midpoint) / (1.0 - self.midpoint)
+        } else {
+            1.0
+        }
+    }
+}
+
+impl<S: Scalar> ColourScale for DivergingColourScale<S> {
+    type Scalar = S;
+
+    fn colour(&self, value: Self::Scalar) -> Colour {
+        (self.interpolator)(self.diverge(self.scale.normalise(value).0))
+    }
+
+    fn stops(&self) -> Vec<ColourStop> {
+        let mut stops = gradient_stops(|location| (self.interpolator)(self.diverge(location)));
+        if (0.0..=1.0).contains(&self.midpoint) {
+            let i = stops
+                .iter()
+                .position(|s| s.location.0 >= self.midpoint)
+                .unwrap_or(stops.len());
+            stops.insert(
+                i,
+                ColourStop {
+                    location: NormalisedValue(self.midpoint),
+                    colour: (self.interpolator)(0.5),
+                },
+            );
+        }
+        stops
+    }
+}
+
+/// Maps values into a fixed number of equally sized bins, each with a distinct colour
+/// sampled evenly from a palette
+#[derive(Clone)]
+pub struct QuantizedColourScale<S: Scalar> {
+    scale: Rc<dyn Scale<Scalar = S>>,
+    bins: usize,
+    interpolator: Rc<dyn Interpolator>,
+}
+
+impl<S: Scalar> QuantizedColourScale<S> {
+    /// Create a new colour scale given a scale to normalise values, the number of
+    /// bins and a palette
+    pub fn new(
+        scale: Rc<dyn Scale<Scalar = S>>,
+        bins: usize,
+        interpolator: Rc<dyn Interpolator>,
+    ) -> QuantizedColourScale<S> {
+        QuantizedColourScale {
+            scale,
+            bins: bins.max(1),
+            interpolator,
+        }
+    }
+
+    fn bin_colour(&self, bin: usize) -> Colour {
+        if self.bins > 1 {
+            (self.interpolator)(bin as f32 / (self.bins - 1) as f32)
+        } else {
+            (self.interpolator)(0.5)
+        }
+    }
+}
+
+impl<S: Scalar> ColourScale for QuantizedColourScale<S> {
+    type Scalar = S;
+
+    fn colour(&self, value: Self::Scalar) -> Colour {
+        let location = self.scale.normalise(value).0.clamp(0.0, 1.0);
+        let bin = ((location * self.bins as f32) as usize).min(self.bins - 1);
+        self.bin_colour(bin)
+    }
+
+    fn stops(&self) -> Vec<ColourStop> {
+        (0..self.bins)
+            .flat_map(|bin| {
+                let colour = self.bin_colour(bin);
+                [bin, bin + 1].map(|edge| ColourStop {
+                    location: NormalisedValue(edge as f32 / self.bins as f32),
+                    colour,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Maps values to colours given a list of ascending thresholds. Values below the first threshold
+/// receive the first colour, values at or above the first threshold and below the second receive
+/// the second colour, and so on. There should be one more colour than there are thresholds.
+#[derive(Clone)]
+pub struct ThresholdColourScale<S: Scalar> {
+    scale: Rc<dyn Scale<Scalar = S>>,
+    thresholds: Vec<S>,
+    colours: Vec<Colour>,
+}
+
+impl<S: Scalar> ThresholdColourScale<S> {
+    /// Create a new colour scale given a scale to normalise thresholds for legends,
+    /// the thresholds and their colours
+    pub fn new(
+        scale: Rc<dyn Scale<Scalar = S>>,
+        thresholds: Vec<S>,
+        colours: Vec<Colour>,
+    ) -> ThresholdColourScale<S> {
+        ThresholdColourScale {
+            scale,
+            thresholds,
+            colours,
+        }
+    }
+
+    fn bin_colour(&self, bin: usize) -> Colour {
+        self.colours
+            .get(bin)
+            .or_else(|| self.colours.last())
+            .copied()
+            .unwrap_or(Colour::rgb(0, 0, 0))
+    }
+}
+
+impl<S: Scalar> ColourScale for ThresholdColourScale<S> {
+    type Scalar = S;
+
+    fn colour(&self, value: Self::Scalar) -> Colour {
+        let bin = self.thresholds.iter().take_while(|t| value >= **t).count();
+        self.bin_colour(bin)
+    }
+
+    fn stops(&self) -> Vec<ColourStop> {
+        let edges = self
+            .thresholds
+            .iter()
+            .map(|t| self.scale.normalise(*t).0.clamp(0.0, 1.0));
+        let starts = std::iter::once(0.0).chain(edges.clone());
+        let ends = edges.chain(std::iter::once(1.0));
+        starts
+            .zip(ends)
+            .enumerate()
+            .flat_map(|(bin, (start, end))| {
+                let colour = self.bin_colour(bin);
+                [start, end].map(|location| ColourStop {
+                    location: NormalisedValue(location),
+                    colour,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::linear_axis_scale::LinearScale;
+
+    fn scale() -> Rc<dyn Scale<Scalar = f32>> {
+        Rc::new(LinearScale::new(0.0..100.0, 25.0))
+    }
+
+    #[test]
+    fn test_colour() {
+        assert_eq!(Colour::hex(0x440154), Colour::rgb(0x44, 0x01, 0x54));
+        assert_eq!(Colour::hex(0x440154).to_string(), "#440154");
+        assert_eq!(
+            Colour::rgb(0, 0, 0).mix(&Colour::rgb(255, 100, 10), 0.5),
+            Colour::rgb(128, 50, 5)
+        );
+    }
+
+    #[test]
+    fn test_sequential_scale() {
+        let colour_scale = SequentialColourScale::new(scale(), Rc::new(viridis()));
+
+        assert_eq!(colour_scale.colour(0.0), Colour::hex(0x440154));
+        assert_eq!(colour_scale.colour(100.0), Colour::hex(0xfde725));
+        assert_eq!(colour_scale.colour(200.0), Colour::hex(0xfde725));
+        assert_eq!(colour_scale.stops().len(), GRADIENT_STOPS + 1);
+    }
+
+    #[test]
+    fn test_diverging_scale() {
+        let colour_scale = DivergingColourScale::new(scale(), 25.0, Rc::new(red_blue()));
+
+        assert_eq!(colour_scale.colour(0.0), Colour::hex(0x67001f));
+        assert_eq!(colour_scale.colour(25.0), Colour::hex(0xf7f7f7));
+        assert_eq!(colour_scale.colour(100.0), Colour::hex(0x053061));
+        assert!(colour_scale.stops().contains(&ColourStop {
+            location: NormalisedValue(0.25),
+            colour: Colour::hex(0xf7f7f7)
+        }));
+    }
+
+    #[test]
+    fn test_quantized_scale() {
+        let colour_scale = QuantizedColourScale::new(
+            scale(),
+            3,
+            Rc::new(palette(vec![Colour::hex(0x000000), Colour::hex(0xffffff)])),
+        );
+
+        assert_eq!(colour_scale.colour(10.0), Colour::hex(0x000000));
+        assert_eq!(colour_scale.colour(50.0), Colour::hex(0x808080));
+        assert_eq!(colour_scale.colour(100.0), Colour::hex(0xffffff));
+        assert_eq!(colour_scale.stops().len(), 6);
+    }
+
+    #[test]
+    fn test_threshold_scale() {
+        let colour_scale = ThresholdColourScale::new(
+            scale(),
+            vec![25.0, 75.0],
+            vec![
+                Colour::hex(0xff0000),
+                Colour::hex(0x00ff00),
+                Colour::hex(0x0000ff),
+            ],
+        );
+
+        assert_eq!(colour_scale.colour(0.0), Colour::hex(0xff0000));
+        assert_eq!(colour_scale.colour(25.0), Colour::hex(0x00ff00));
+        assert_eq!(colour_scale.colour(80.0), Colour::hex(0x0000ff));
+        assert_eq!(
+            colour_scale.stops(),
+            vec![
+                ColourStop {
+                    location: NormalisedValue(0.0),
+                    colour: Colour::hex(0xff0000)
+                },
+                ColourStop {
+                    location: NormalisedValue(0.25),
+                    colour: Colour::hex(0xff0000)
+                },
+                ColourStop {
+                    location: NormalisedValue(0.25),
+                    colour: Colour::hex(0x00ff00)
+                },
+                ColourStop {
+                    location: NormalisedValue(0.75),
+                    colour: Colour::hex(0x00ff00)
+                },
+                ColourStop {
+                    location: NormalisedValue(0.75),
+                    colour: Colour::hex(0x0000ff)
+                },
+                ColourStop {
+                    location: NormalisedValue(1.0),
+                    colour: Colour::hex(0x0000ff)
+                },
+            ]
+        );
+    }
+}
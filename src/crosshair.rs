@@ -0,0 +1,613 @@
+/// A Crosshair is an interaction overlay that tracks the pointer over the plot area of one or
+/// more series. The data points nearest to the pointer are found by projecting each series' points
+/// into SVG space with the scales, a crosshair is drawn through them, and a callback receives the
+/// matched data values. The pointer's own position is converted into data values using the
+/// inverse mapping of the scales, and so the scales must be an [InvertibleScale].
+///
+/// The same scales are normally also given to a [Series](crate::series::Series) and an
+/// [Axis](crate::axis::Axis), which take a [Scale](crate::axis::Scale). Keep each scale as its
+/// concrete type and cast a clone to the trait object that each component expects, e.g.
+/// `Rc::clone(&scale) as Rc<dyn Scale<Scalar = _>>` for a series and
+/// `Rc::clone(&scale) as Rc<dyn InvertibleScale<Scalar = _>>` for a crosshair, where `scale` is
+/// an `Rc<LinearScale>`. Casting an `Rc<dyn InvertibleScale>` to an `Rc<dyn Scale>` instead
+/// relies on trait upcasting, which requires Rust 1.86 or later.
+///
+/// The overlay is drawn as a transparent rectangle over the plot area and so it should be declared
+/// after any series so that it receives pointer events. The data and scales are expected to be the
+/// same ones provided to the chart's [Series](crate::series::Series). Series named by the hidden
+/// property, such as those hidden via a [Legend](crate::legend::Legend), are not tracked.
+///
+/// With the custom-tooltip feature, a tooltip listing the values of every series at the pointer
+/// may be rendered with a [Tooltipper] such as [series_tooltip] and shown through a
+/// [TooltipHandle].
+///
+/// Crosshairs given a group within a [CursorProvider](crate::cursor::CursorProvider) are
+/// synchronised. Hovering over one of them shows the vertical line, matched points and any
+/// tooltip at the same x value on the others.
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * crosshair - the crosshair as a whole
+/// * vertical - the vertical line
+/// * horizontal - the horizontal line
+/// * point - a circle highlighting a matched point, along with the series name
+/// * overlay - the transparent rectangle receiving pointer events
+use std::{marker::PhantomData, rc::Rc};
+
+use web_sys::Element;
+#[cfg(feature = "custom-tooltip")]
+use web_sys::MouseEvent;
+use yew::{context::ContextHandle, prelude::*};
+
+#[cfg(feature = "custom-tooltip")]
+use crate::tooltip::TooltipHandle;
+use crate::{
+    axis::{InvertibleScale, NormalisedValue},
+    cursor::CursorContext,
+    series::{NamedData, Scalar},
+};
+
+const POINT_RADIUS: f32 = 3.0;
+
+/// Describes how the pointer is snapped to data points
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Snap {
+    /// Matches the point of each series nearest to the pointer along the x axis
+    X,
+    /// Matches the single point across all series nearest to the pointer
+    Nearest,
+}
+
+/// A data point matched by the pointer
+#[derive(Clone, Debug, PartialEq)]
+pub struct Match<A, B> {
+    /// The name of the series the point belongs to
+    pub name: AttrValue,
+    /// The index of the point within its series data
+    pub index: usize,
+    /// The x value of the point
+    pub x: A,
+    /// The y value of the point
+    pub y: B,
+}
+
+/// Describes the position of the pointer in data space along with the points matched by it
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hover<A, B> {
+    /// The x value at the pointer
+    pub x: A,
+    /// The y value at the pointer
+    pub y: B,
+    /// The matched points, in the order of their series
+    pub matches: Vec<Match<A, B>>,
+}
+
+/// Describes a closure that takes the pointer's position and matched points and produces rich
+/// tooltip Html for them.
+pub trait Tooltipper<A: Scalar, B: Scalar>: Fn(&Hover<A, B>) -> Html {}
+
+impl<A: Scalar, B: Scalar, T: Fn(&Hover<A, B>) -> Html> Tooltipper<A, B> for T {}
+
+/// A tooltip listing the x value of the first matched point, followed by the name and y value of
+/// each matched point along with a swatch that may be styled with its series name. Values are
+/// formatted with the labellers provided.
+///
+/// The following styling properties are available:
+///
+/// * series-tooltip - the tooltip as a whole
+/// * x - the x value
+/// * entry - the row of a matched point
+/// * swatch - the swatch of a matched point, along with its series name
+/// * name - the series name of a matched point
+/// * y - the y value of a matched point
+pub fn series_tooltip<A: Scalar, B: Scalar>(
+    x_labeller: impl Fn(A) -> String,
+    y_labeller: impl Fn(B) -> String,
+) -> impl Tooltipper<A, B> {
+    move |hover: &Hover<A, B>| {
+        let entries = hover.matches.iter().map(|m| {
+            html! {
+                <div class="entry">
+                    <span class={classes!("swatch", &m.name)} />
+                    <span class="name">{&m.name}</span>
+                    <span class="y">{y_labeller(m.y)}</span>
+                </div>
+            }
+        });
+        html! {
+            <div class="series-tooltip">
+                if let Some(m) = hover.matches.first() {
+                    <div class="x">{x_labeller(m.x)}</div>
+                }
+                { for entries }
+            </div>
+        }
+    }
+}
+
+/// Finds the points nearest to a pointer given the SVG positions of each series' points.
+/// Returns the series and point indices of each match.
+fn find_matches(
+    positions: &[Vec<(f32, f32)>],
+    pointer: (f32, f32),
+    snap: Snap,
+) -> Vec<(usize, usize)> {
+    let nearest = |series: &[(f32, f32)], distance: &dyn Fn(&(f32, f32)) -> f32| {
+        series
+            .iter()
+            .enumerate()
+            .filter(|(_, (x, y))| x.is_finite() && y.is_finite())
+            .map(|(i, p)| (i, distance(p)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+    };
+
+    match snap {
+        Snap::X => positions
+            .iter()
+            .enumerate()
+            .filter_map(|(s, series)| {
+                nearest(series, &|(x, _)| (x - pointer.0).abs()).map(|(i, _)| (s, i))
+            })
+            .collect(),
+        Snap::Nearest => positions
+            .iter()
+            .enumerate()
+            .filter_map(|(s, series)| {
+                nearest(series, &|(x, y)| {
+                    (x - pointer.0).powi(2) + (y - pointer.1).powi(2)
+                })
+                .map(|(i, d)| (s, i, d))
+            })
+            .min_by(|(_, _, a), (_, _, b)| a.total_cmp(b))
+            .map(|(s, i, _)| vec![(s, i)])
+            .unwrap_or_default(),
+    }
+}
+
+pub enum Msg<A> {
+    Move(f32, f32),
+    Leave,
+    Cursor(CursorContext<A>),
+    #[cfg(feature = "custom-tooltip")]
+    Tooltip(MouseEvent),
+}
+
+#[derive(Properties, Clone)]
+pub struct Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    /// The data of the series to be tracked
+    pub data: Rc<NamedData<A, B>>,
+    /// Any group of crosshairs to be synchronised with via a
+    /// [CursorProvider](crate::cursor::CursorProvider)
+    #[prop_or_default]
+    pub group: Option<AttrValue>,
+    /// The SVG height of the plot area
+    pub height: f32,
+    /// The names of any series that are hidden, and so not tracked
+    #[prop_or_default]
+    pub hidden: Vec<AttrValue>,
+    /// The scaling factor for data along the x axis
+    pub horizontal_scale: Rc<dyn InvertibleScale<Scalar = A>>,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    /// A callback to receive the pointer's position and matched points as the pointer moves, and
+    /// None when the pointer leaves the plot area
+    #[prop_or_default]
+    pub onhover: Callback<Option<Hover<A, B>>>,
+    /// How the pointer is snapped to data points
+    #[prop_or(Snap::X)]
+    pub snap: Snap,
+    #[cfg(feature = "custom-tooltip")]
+    /// The handle through which tooltips are shown. Requires the custom-tooltip feature.
+    #[prop_or_default]
+    pub tooltip: Option<TooltipHandle>,
+    #[cfg(feature = "custom-tooltip")]
+    /// An optional function that renders Html for the points matched by the pointer, being
+    /// shown through the tooltip handle. Requires the custom-tooltip feature.
+    #[prop_or_default]
+    pub tooltipper: Option<Rc<dyn Tooltipper<A, B>>>,
+    /// The scaling factor for data along the y axis
+    pub vertical_scale: Rc<dyn InvertibleScale<Scalar = B>>,
+    /// The SVG width of the plot area
+    pub width: f32,
+    /// The start position
+    pub x: f32,
+    /// The start position
+    pub y: f32,
+}
+
+impl<A, B> Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    #[cfg(feature = "custom-tooltip")]
+    fn is_tooltip_eq(&self, other: &Self) -> bool {
+        self.tooltip == other.tooltip
+            && match (self.tooltipper.as_ref(), other.tooltipper.as_ref()) {
+                (Some(left), Some(right)) => std::ptr::eq(
+                    left as *const _ as *const u8,
+                    right as *const _ as *const u8,
+                ),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+    #[cfg(not(feature = "custom-tooltip"))]
+    fn is_tooltip_eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<A, B> PartialEq for Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.data, &other.data)
+            && self.group == other.group
+            && self.height == other.height
+            && self.hidden == other.hidden
+            && self.name == other.name
+            && self.onhover == other.onhover
+            && self.is_tooltip_eq(other)
+            && self.snap == other.snap
+            && self.width == other.width
+            && self.x == other.x
+            && self.y == other.y
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.horizontal_scale as *const _ as *const u8,
+                &*other.horizontal_scale as *const _ as *const u8,
+            )
+            && std::ptr::eq(
+                &*self.vertical_scale as *const _ as *const u8,
+                &*other.vertical_scale as *const _ as *const u8,
+            )
+    }
+}
+
+pub struct Crosshair<A: 'static, B>
+where
+    A: Scalar,
+{
+    cursor: Option<(CursorContext<A>, ContextHandle<CursorContext<A>>)>,
+    overlay: NodeRef,
+    phantom: PhantomData<(A, B)>,
+    pointer: Option<(f32, f32)>,
+    matches: Vec<(usize, usize)>,
+    remote: Option<f32>,
+}
+
+impl<A: 'static, B: 'static> Crosshair<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    fn subscribe(
+        ctx: &Context<Self>,
+    ) -> Option<(CursorContext<A>, ContextHandle<CursorContext<A>>)> {
+        ctx.props().group.as_ref()?;
+        ctx.link()
+            .context::<CursorContext<A>>(ctx.link().callback(Msg::Cursor))
+    }
+
+    // Publishes the x value at a pointer to the crosshair's group, or clears it
+    fn publish(&self, p: &Props<A, B>, pointer: Option<(f32, f32)>) {
+        if let (Some((cursor, _)), Some(group)) = (&self.cursor, &p.group) {
+            cursor.set(
+                group.clone(),
+                pointer.map(|(x, _)| p.horizontal_scale.denormalise(NormalisedValue(x / p.width))),
+            );
+        }
+    }
+
+    // Follows the cursor of the crosshair's group when the pointer is not over this crosshair
+    fn follow(&mut self, p: &Props<A, B>) {
+        if self.pointer.is_some() {
+            return;
+        }
+        self.remote = self
+            .cursor
+            .as_ref()
+            .zip(p.group.as_ref())
+            .and_then(|((cursor, _), group)| cursor.get(group))
+            .map(|x| p.horizontal_scale.normalise(x).0 * p.width);
+        self.matches = self
+            .remote
+            .map(|x| find_matches(&Self::positions(p), (x, 0.0), Snap::X))
+            .unwrap_or_default();
+    }
+
+    // Shows or hides the tooltip for the cursor of the crosshair's group, positioned at the
+    // first matched point as if the pointer were over it.
+    #[cfg(feature = "custom-tooltip")]
+    fn remote_tooltip(&self, p: &Props<A, B>) {
+        let (handle, overlay) = match (&p.tooltip, self.overlay.cast::<Element>()) {
+            (Some(handle), Some(overlay)) => (handle, overlay),
+            _ => return,
+        };
+        match (&p.tooltipper, self.remote) {
+            (Some(tt), Some(x)) => {
+                let pointer = self
+                    .matches
+                    .first()
+                    .map(|(s, i)| Self::positions(p)[*s][*i])
+                    .unwrap_or((x, p.height * 0.5));
+                let rect = overlay.get_bounding_client_rect();
+                handle.show_at(
+                    (rect.left() + (pointer.0 / p.width) as f64 * rect.width()) as f32,
+                    (rect.top() + (pointer.1 / p.height) as f64 * rect.height()) as f32,
+                    tt(&self.hover(p, pointer)),
+                );
+            }
+            _ => handle.hide(),
+        }
+    }
+
+    fn hover(&self, p: &Props<A, B>, (x, y): (f32, f32)) -> Hover<A, B> {
+        let matches = self
+            .matches
+            .iter()
+            .map(|(s, i)| {
+                let (name, data) = &p.data[*s];
+                let (x, y, _) = data[*i];
+                Match {
+                    name: name.clone(),
+                    index: *i,
+                    x,
+                    y,
+                }
+            })
+            .collect();
+        Hover {
+            x: p.horizontal_scale.denormalise(NormalisedValue(x / p.width)),
+            y: p.vertical_scale
+                .denormalise(NormalisedValue((p.height - y) / p.height)),
+            matches,
+        }
+    }
+
+    // The SVG positions of each series' points relative to the plot area, with points outside
+    // of the plot area's x bounds and those of hidden series being excluded.
+    fn positions(p: &Props<A, B>) -> Vec<Vec<(f32, f32)>> {
+        let x_bounds = -0.1..=p.width + 0.1;
+        p.data
+            .iter()
+            .map(|(name, data)| {
+                let hidden = p.hidden.contains(name);
+                data.iter()
+                    .map(|(x, y, _)| {
+                        let x = p.horizontal_scale.normalise(*x).0 * p.width;
+                        let y = p.height - p.vertical_scale.normalise(*y).0 * p.height;
+                        if !hidden && x_bounds.contains(&x) {
+                            (x, y)
+                        } else {
+                            (f32::NAN, f32::NAN)
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<A: 'static, B: 'static> Component for Crosshair<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    type Message = Msg<A>;
+
+    type Properties = Props<A, B>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let mut crosshair = Crosshair {
+            cursor: Self::subscribe(ctx),
+            overlay: NodeRef::default(),
+            phantom: PhantomData,
+            pointer: None,
+            matches: vec![],
+            remote: None,
+        };
+        crosshair.follow(ctx.props());
+        crosshair
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let p = ctx.props();
+        match msg {
+            Msg::Move(x, y) => {
+                self.matches = find_matches(&Self::positions(p), (x, y), p.snap);
+                self.pointer = Some((x, y));
+                self.remote = None;
+                p.onhover.emit(Some(self.hover(p, (x, y))));
+                self.publish(p, self.pointer);
+            }
+            #[cfg(feature = "custom-tooltip")]
+            Msg::Tooltip(e) => {
+                if let (Some(handle), Some(tt), Some(pointer)) =
+                    (&p.tooltip, &p.tooltipper, self.pointer)
+                {
+                    handle
+                        .html_callback()
+                        .emit((e, tt(&self.hover(p, pointer))));
+                }
+                return false;
+            }
+            Msg::Leave => {
+                self.matches.clear();
+                self.pointer = None;
+                p.onhover.emit(None);
+                self.publish(p, None);
+            }
+            Msg::Cursor(cursor) => {
+                self.cursor = self.cursor.take().map(|(_, handle)| (cursor, handle));
+                if self.pointer.is_some() {
+                    return false;
+                }
+                let remote = self.remote;
+                self.follow(p);
+                #[cfg(feature = "custom-tooltip")]
+                if self.remote.is_some() || remote.is_some() {
+                    self.remote_tooltip(p);
+                }
+                return self.remote != remote;
+            }
+        }
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let p = ctx.props();
+        if p.group != old_props.group {
+            self.cursor = Self::subscribe(ctx);
+        }
+        match self.pointer {
+            Some(pointer) => self.matches = find_matches(&Self::positions(p), pointer, p.snap),
+            None => self.follow(p),
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let p = ctx.props();
+
+        let (width, height) = (p.width, p.height);
+        let onpointermove = ctx.link().batch_callback(move |e: PointerEvent| {
+            let rect = e
+                .target_unchecked_into::<Element>()
+                .get_bounding_client_rect();
+            if rect.width() <= 0.0 || rect.height() <= 0.0 {
+                return vec![];
+            }
+            vec![
+                Msg::Move(
+                    ((e.client_x() as f64 - rect.left()) / rect.width()) as f32 * width,
+                    ((e.client_y() as f64 - rect.top()) / rect.height()) as f32 * height,
+                ),
+                #[cfg(feature = "custom-tooltip")]
+                Msg::Tooltip((*e).clone()),
+            ]
+        });
+        let onpointerleave = ctx.link().callback(|_: PointerEvent| Msg::Leave);
+
+        let positions = Self::positions(p);
+        let points = self
+            .matches
+            .iter()
+            .map(|(s, i)| (*s, positions[*s][*i]))
+            .collect::<Vec<_>>();
+
+        let remote = self.remote.map(|x| {
+            let x = points.first().map(|(_, (x, _))| *x).unwrap_or(x) + p.x;
+            html! {
+                <line x1={x.to_string()} y1={p.y.to_string()} x2={x.to_string()} y2={(p.y + p.height).to_string()} class="vertical" />
+            }
+        });
+        let crosshair = self.pointer.map(|(px, py)| {
+            let (cx, cy) = match (p.snap, points.first()) {
+                (Snap::X, Some((_, (x, _)))) => (*x, py),
+                (Snap::Nearest, Some((_, (x, y)))) => (*x, *y),
+                _ => (px, py),
+            };
+            let (cx, cy) = (cx + p.x, cy + p.y);
+            html! {
+                <>
+                <line x1={cx.to_string()} y1={p.y.to_string()} x2={cx.to_string()} y2={(p.y + p.height).to_string()} class="vertical" />
+                <line x1={p.x.to_string()} y1={cy.to_string()} x2={(p.x + p.width).to_string()} y2={cy.to_string()} class="horizontal" />
+                </>
+            }
+        });
+        let circles = points.iter().map(|(s, (x, y))| {
+            html! {
+                <circle cx={(x + p.x).to_string()} cy={(y + p.y).to_string()} r={POINT_RADIUS.to_string()}
+                    class={classes!("point", &p.data[*s].0)} />
+            }
+        });
+
+        html! {
+            <g class={classes!("crosshair", &p.name)}>
+                <g pointer-events="none">
+                    { for crosshair }
+                    { for remote }
+                    { for circles }
+                </g>
+                <rect ref={self.overlay.clone()} x={p.x.to_string()} y={p.y.to_string()} width={p.width.to_string()} height={p.height.to_string()}
+                    fill="transparent" class="overlay"
+                    onpointermove={onpointermove} onpointerleave={onpointerleave} />
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_matches_x() {
+        let positions = vec![
+            vec![(0.0, 10.0), (10.0, 20.0), (20.0, 5.0)],
+            vec![(5.0, 0.0), (f32::NAN, f32::NAN), (16.0, 0.0)],
+            vec![],
+        ];
+
+        assert_eq!(
+            find_matches(&positions, (14.0, 0.0), Snap::X),
+            vec![(0, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn test_find_matches_nearest() {
+        let positions = vec![
+            vec![(0.0, 10.0), (10.0, 20.0), (20.0, 5.0)],
+            vec![(5.0, 0.0), (16.0, 0.0)],
+        ];
+
+        assert_eq!(
+            find_matches(&positions, (14.0, 18.0), Snap::Nearest),
+            vec![(0, 1)]
+        );
+        assert_eq!(
+            find_matches(&positions, (14.0, 2.0), Snap::Nearest),
+            vec![(1, 1)]
+        );
+        assert_eq!(find_matches(&[], (0.0, 0.0), Snap::Nearest), vec![]);
+    }
+
+    #[test]
+    fn test_positions_skip_hidden() {
+        use crate::linear_axis_scale::LinearScale;
+
+        let scale: Rc<dyn InvertibleScale<Scalar = f32>> =
+            Rc::new(LinearScale::new(0.0..10.0, 1.0));
+        let props = yew::props!(Props<f32, f32> {
+            data: Rc::new(vec![
+                (AttrValue::from("a"), Rc::new(vec![(5.0, 5.0, None)])),
+                (AttrValue::from("b"), Rc::new(vec![(5.0, 2.0, None)])),
+            ]),
+            height: 100.0,
+            hidden: vec![AttrValue::from("b")],
+            horizontal_scale: scale.clone(),
+            name: "crosshair",
+            vertical_scale: scale,
+            width: 100.0,
+            x: 0.0,
+            y: 0.0,
+        });
+        let positions = Crosshair::positions(&props);
+
+        assert_eq!(positions[0], vec![(50.0, 50.0)]);
+        assert!(positions[1][0].0.is_nan());
+        assert_eq!(find_matches(&positions, (48.0, 0.0), Snap::X), vec![(0, 0)]);
+    }
+}
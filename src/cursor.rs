@@ -0,0 +1,103 @@
+/// A CursorProvider synchronises the cursors of multiple charts, such as a stack of charts
+/// sharing the same time axis. The charts are provided as the component's children, and each
+/// [Crosshair](crate::crosshair::Crosshair) given a group subscribes to the provider's
+/// [CursorContext]. Hovering over one chart of a group then shows the crosshair at the same x
+/// value on every other chart of the group.
+///
+/// Cursors are shared as x values in data units and so the charts of a group are expected to
+/// have x scales of the same type, although their domains and sizes may differ.
+use std::{collections::HashMap, rc::Rc};
+
+use yew::prelude::*;
+
+use crate::series::Scalar;
+
+/// The cursors of each group, along with a callback to set or clear the cursor of a group.
+/// Contexts are compared by reference.
+#[derive(Clone)]
+pub struct CursorContext<A> {
+    cursors: Rc<HashMap<AttrValue, A>>,
+    set: Callback<(AttrValue, Option<A>)>,
+}
+
+impl<A> CursorContext<A>
+where
+    A: Scalar,
+{
+    /// The x value of the cursor of a group, if any
+    pub fn get(&self, group: &AttrValue) -> Option<A> {
+        self.cursors.get(group).copied()
+    }
+
+    /// Sets the cursor of a group, or clears it when None
+    pub fn set(&self, group: AttrValue, x: Option<A>) {
+        self.set.emit((group, x));
+    }
+}
+
+impl<A> PartialEq for CursorContext<A> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.cursors, &other.cursors) && self.set == other.set
+    }
+}
+
+pub enum Msg<A> {
+    Set(AttrValue, Option<A>),
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct Props {
+    /// The charts whose cursors are synchronised
+    pub children: Html,
+}
+
+pub struct CursorProvider<A> {
+    cursors: Rc<HashMap<AttrValue, A>>,
+    set: Callback<(AttrValue, Option<A>)>,
+}
+
+impl<A: 'static> Component for CursorProvider<A>
+where
+    A: Scalar,
+{
+    type Message = Msg<A>;
+
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        CursorProvider {
+            cursors: Rc::default(),
+            set: ctx.link().callback(|(group, x)| Msg::Set(group, x)),
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Set(group, x) => {
+                if self.cursors.get(&group) == x.as_ref() {
+                    return false;
+                }
+                let mut cursors = (*self.cursors).clone();
+                match x {
+                    Some(x) => cursors.insert(group, x),
+                    None => cursors.remove(&group),
+                };
+                self.cursors = Rc::new(cursors);
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let context = CursorContext {
+            cursors: Rc::clone(&self.cursors),
+            set: self.set.clone(),
+        };
+
+        html! {
+            <ContextProvider<CursorContext<A>> context={context}>
+                {ctx.props().children.clone()}
+            </ContextProvider<CursorContext<A>>>
+        }
+    }
+}
@@ -0,0 +1,258 @@
+/// EventMarkers annotate a time chart with events, such as irrigation or sensor maintenance, by
+/// drawing a small flag for each event along an edge of the plot area. Flags are drawn upwards
+/// from the edge's y position, and so are typically placed along the top of a bottom axis.
+///
+/// Events that are too close together at the current scale are clustered into a single flag that
+/// displays the number of events it represents. As the scale changes, such as when zooming,
+/// clusters are recomputed.
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * event-markers - the markers as a whole
+/// * event - a flag representing a single event, along with the event's category
+/// * cluster - a flag representing multiple events
+/// * pole - the line of a flag
+/// * flag - the background of a flag
+/// * icon - the text within a flag
+/// * label - the text of an event's label
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::axis::Scale;
+#[cfg(feature = "custom-tooltip")]
+use crate::series::TooltipCallback;
+
+const LABEL_OFFSET: f32 = 3.0;
+
+/// Describes an event to be marked
+#[derive(Clone, Debug, PartialEq)]
+pub struct Event {
+    /// The time of the event as a timestamp in milliseconds
+    pub timestamp: i64,
+    /// Any short text, such as a symbol, to be drawn within the event's flag
+    pub icon: Option<String>,
+    /// Any label to be drawn alongside the event's flag
+    pub label: Option<String>,
+    /// Any category to be used for CSS selection
+    pub category: Option<String>,
+}
+
+/// Describes the events to be marked
+pub type Data = Vec<Event>;
+
+/// Describes a closure that takes an event and produces a tooltip string for it. The tooltip of a
+/// cluster is formed by joining the tooltips of its events with newlines.
+pub trait Tooltipper: Fn(&Event) -> String {}
+
+impl<T: Fn(&Event) -> String> Tooltipper for T {}
+
+/// Basic tooltip that outputs an event's label
+pub fn label_tooltip() -> impl Tooltipper {
+    |event: &Event| event.label.clone().unwrap_or_default()
+}
+
+/// Groups indices of positions such that each group's positions are within a distance of the
+/// group's first position. Groups are returned in order of position.
+fn cluster(positions: &[f32], distance: f32) -> Vec<Vec<usize>> {
+    let mut order = (0..positions.len()).collect::<Vec<_>>();
+    order.sort_by(|a, b| positions[*a].total_cmp(&positions[*b]));
+
+    let mut clusters: Vec<Vec<usize>> = vec![];
+    for i in order {
+        match clusters.last_mut() {
+            Some(c) if positions[i] - positions[c[0]] < distance => c.push(i),
+            _ => clusters.push(vec![i]),
+        }
+    }
+    clusters
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    /// The minimum SVG distance between flags. Events closer than this are clustered.
+    #[prop_or(12.0)]
+    pub cluster_distance: f32,
+    /// The events to be marked
+    pub data: Rc<Data>,
+    /// The SVG height of each flag
+    #[prop_or(20.0)]
+    pub flag_height: f32,
+    /// The SVG width of each flag
+    #[prop_or(10.0)]
+    pub flag_width: f32,
+    /// The scaling factor for timestamps along the x axis
+    pub horizontal_scale: Rc<dyn Scale<Scalar = i64>>,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    #[cfg(feature = "custom-tooltip")]
+    /// A callback to receive mouseover events along with tooltipper function text results. Requires
+    /// the custom-tooltip feature.
+    #[prop_or_else(|| Rc::new(Callback::noop()))]
+    pub onmouseover: Rc<TooltipCallback>,
+    /// An optional function that renders a string to be used for tooltips
+    #[prop_or_default]
+    pub tooltipper: Option<Rc<dyn Tooltipper>>,
+    /// The SVG width of the plot area
+    pub width: f32,
+    /// The start position
+    pub x: f32,
+    /// The position of the edge that flags are drawn from
+    pub y: f32,
+}
+
+impl Props {
+    #[cfg(feature = "custom-tooltip")]
+    fn is_onmouseover_eq(&self, other: &Self) -> bool {
+        self.onmouseover == other.onmouseover
+    }
+    #[cfg(not(feature = "custom-tooltip"))]
+    fn is_onmouseover_eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl PartialEq for Props {
+    fn eq(&self, other: &Self) -> bool {
+        self.cluster_distance == other.cluster_distance
+            && Rc::ptr_eq(&self.data, &other.data)
+            && self.flag_height == other.flag_height
+            && self.flag_width == other.flag_width
+            && self.name == other.name
+            && self.is_onmouseover_eq(other)
+            && match (self.tooltipper.as_ref(), other.tooltipper.as_ref()) {
+                (Some(left), Some(right)) => std::ptr::eq(left as *const _ as *const u8, right as *const _ as *const u8),
+                _=> false
+            }
+            && self.width == other.width
+            && self.x == other.x
+            && self.y == other.y
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.horizontal_scale as *const _ as *const u8,
+                &*other.horizontal_scale as *const _ as *const u8,
+            )
+    }
+}
+
+pub struct EventMarkers;
+
+impl Component for EventMarkers {
+    type Message = ();
+
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        EventMarkers
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        #[cfg(feature = "custom-tooltip")]
+        fn onmouseover(cb: &Rc<TooltipCallback>, title: String) -> impl Fn(MouseEvent) {
+            let cb = Rc::clone(cb);
+            move |e| {
+                (*cb).emit((e, title.clone()));
+            }
+        }
+
+        let p = ctx.props();
+
+        let x_bounds = -0.1..=p.width + 0.1;
+        let (visible, positions): (Vec<_>, Vec<_>) = p
+            .data
+            .iter()
+            .map(|event| p.horizontal_scale.normalise(event.timestamp).0 * p.width)
+            .enumerate()
+            .filter(|(_, x)| x_bounds.contains(x))
+            .unzip();
+
+        let flags = cluster(&positions, p.cluster_distance)
+            .into_iter()
+            .map(|indices| {
+                let events = indices
+                    .iter()
+                    .map(|i| &p.data[visible[*i]])
+                    .collect::<Vec<_>>();
+                let x = p.x
+                    + indices.iter().map(|i| positions[*i]).sum::<f32>() / indices.len() as f32;
+                let top = p.y - p.flag_height;
+
+                let (class, icon, label) = match events.as_slice() {
+                    [event] => (
+                        classes!("event", event.category.clone()),
+                        event.icon.clone(),
+                        event.label.clone(),
+                    ),
+                    _ => (
+                        classes!("cluster"),
+                        Some(events.len().to_string()),
+                        None,
+                    ),
+                };
+                let title = p.tooltipper.as_ref().map(|tt| {
+                    events
+                        .iter()
+                        .map(|e| tt(e))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                });
+
+                #[cfg(feature = "custom-tooltip")]
+                let flag = html! {
+                    <rect x={x.to_string()} y={top.to_string()} width={p.flag_width.to_string()} height={(p.flag_height * 0.5).to_string()}
+                        class="flag"
+                        onmouseover={onmouseover(&p.onmouseover, title.unwrap_or_default())} />
+                };
+                #[cfg(not(feature = "custom-tooltip"))]
+                let flag = html! {
+                    <rect x={x.to_string()} y={top.to_string()} width={p.flag_width.to_string()} height={(p.flag_height * 0.5).to_string()}
+                        class="flag">
+                        if let Some(t) = title {
+                            <title>{t}</title>
+                        }
+                    </rect>
+                };
+
+                html! {
+                    <g class={class}>
+                        <line x1={x.to_string()} y1={p.y.to_string()} x2={x.to_string()} y2={top.to_string()} class="pole" />
+                        {flag}
+                        if let Some(i) = icon {
+                            <text x={(x + p.flag_width * 0.5).to_string()} y={(top + p.flag_height * 0.25).to_string()}
+                                text-anchor="middle" dominant-baseline="middle" pointer-events="none" class="icon">{i}</text>
+                        }
+                        if let Some(l) = label {
+                            <text x={(x + p.flag_width + LABEL_OFFSET).to_string()} y={(top + p.flag_height * 0.25).to_string()}
+                                dominant-baseline="middle" class="label">{l}</text>
+                        }
+                    </g>
+                }
+            });
+
+        html! {
+            <g class={classes!("event-markers", &p.name)}>
+                { for flags }
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cluster() {
+        let positions = [50.0, 0.0, 5.0, 30.0, 11.0, 100.0];
+
+        assert_eq!(
+            cluster(&positions, 10.0),
+            vec![vec![1, 2], vec![4], vec![3], vec![0], vec![5]]
+        );
+        assert_eq!(cluster(&positions, 0.0).len(), positions.len());
+        assert_eq!(cluster(&[], 10.0), Vec::<Vec<usize>>::new());
+    }
+}
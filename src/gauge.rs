@@ -0,0 +1,241 @@
+/// A Gauge renders a value as a needle on a dial. The dial is an arc, or a full circle, whose
+/// extent represents a scale. Ranges of the scale may be highlighted as coloured bands, such as
+/// to show where a tank level is low, and the scale's ticks are drawn around the outside of the arc.
+///
+/// Angles are expressed in degrees, clockwise from 12 o'clock.
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * gauge - the gauge as a whole
+/// * track - the arc of the dial
+/// * band - a highlighted range of the dial, along with the band's class
+/// * value - the arc of the dial from its minimum to the value
+/// * tick - the tick lines
+/// * text - the tick text
+/// * needle - the needle
+/// * hub - the circle at the base of the needle
+/// * readout - the text displaying the value
+use std::{ops::Range, rc::Rc};
+
+use yew::prelude::*;
+
+use crate::{
+    axis::{NormalisedValue, Scale, Tick},
+    linear_axis_scale::Labeller,
+    polar::{arc_path, is_tick_drawn, text_anchor, to_cartesian, Projection},
+};
+
+/// Describes ranges of the gauge's scale to highlight, along with a class for each
+pub type Bands = Vec<(Range<f32>, String)>;
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    /// The SVG width of the dial's arc
+    pub arc_width: f32,
+    /// Ranges of the scale to highlight
+    #[prop_or_default]
+    pub bands: Rc<Bands>,
+    /// The angle in degrees representing the maximum of the scale
+    #[prop_or(120.0)]
+    pub end_angle: f32,
+    /// An optional function to format the readout of the value. If None then
+    /// no readout is displayed.
+    #[prop_or_default]
+    pub labeller: Option<Rc<dyn Labeller>>,
+    /// A name given to the gauge that will be used for CSS classes
+    pub name: AttrValue,
+    /// The SVG radius of the outside of the dial
+    pub radius: f32,
+    /// The scaling conversion to be used with the gauge
+    pub scale: Rc<dyn Scale<Scalar = f32>>,
+    /// The angle in degrees representing the minimum of the scale
+    #[prop_or(-120.0)]
+    pub start_angle: f32,
+    /// The length of ticks
+    pub tick_len: f32,
+    /// The value to display
+    pub value: f32,
+    /// The centre position
+    pub x: f32,
+    /// The centre position
+    pub y: f32,
+}
+
+impl PartialEq for Props {
+    fn eq(&self, other: &Self) -> bool {
+        self.arc_width == other.arc_width
+            && Rc::ptr_eq(&self.bands, &other.bands)
+            && self.end_angle == other.end_angle
+            && match (self.labeller.as_ref(), other.labeller.as_ref()) {
+                (Some(left), Some(right)) => std::ptr::eq(left as *const _ as *const u8, right as *const _ as *const u8),
+                (None, None) => true,
+                _ => false
+            }
+            && self.name == other.name
+            && self.radius == other.radius
+            && self.start_angle == other.start_angle
+            && self.tick_len == other.tick_len
+            && self.value == other.value
+            && self.x == other.x
+            && self.y == other.y
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.scale as *const _ as *const u8,
+                &*other.scale as *const _ as *const u8,
+            )
+    }
+}
+
+/// The angle of a normalised value, clamped to the extent of the dial.
+fn clamped_angle(projection: &Projection, value: NormalisedValue) -> f32 {
+    projection.angle(&NormalisedValue(value.0.clamp(0.0, 1.0)))
+}
+
+/// The start and end of a tick at an angle around the outside of the dial, followed by the
+/// position of its label.
+fn tick(projection: &Projection, angle: f32, tick_len: f32) -> [(f32, f32); 3] {
+    let (x, y, radius) = (projection.x, projection.y, projection.radius);
+    [
+        to_cartesian(x, y, radius, angle),
+        to_cartesian(x, y, radius + tick_len, angle),
+        to_cartesian(x, y, radius + tick_len * 2.0, angle),
+    ]
+}
+
+/// The ticks of a scale that are drawn around the dial, omitting the maximum of a full circle as
+/// it would coincide with the minimum.
+fn ticks(projection: &Projection, scale: &dyn Scale<Scalar = f32>) -> Vec<Tick> {
+    scale
+        .ticks()
+        .into_iter()
+        .filter(|Tick { location, .. }| is_tick_drawn(projection, location))
+        .collect()
+}
+
+pub struct Gauge;
+
+impl Component for Gauge {
+    type Message = ();
+
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Gauge
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let p = ctx.props();
+
+        let projection = Projection {
+            x: p.x,
+            y: p.y,
+            inner_radius: p.radius - p.arc_width,
+            radius: p.radius,
+            start_angle: p.start_angle.to_radians(),
+            end_angle: p.end_angle.to_radians(),
+        };
+        let clamped_angle = |value: f32| clamped_angle(&projection, p.scale.normalise(value));
+        let sector = |start: f32, end: f32| {
+            arc_path(
+                p.x,
+                p.y,
+                projection.inner_radius,
+                projection.radius,
+                start,
+                end,
+            )
+        };
+
+        let bands = p.bands.iter().map(|(range, class)| {
+            html! {
+                <path d={sector(clamped_angle(range.start), clamped_angle(range.end))} class={classes!("band", class)} />
+            }
+        });
+
+        let ticks = ticks(&projection, &*p.scale).into_iter().map(|Tick { location, label }| {
+            let angle = projection.angle(&location);
+            let [(x1, y1), (x2, y2), (x3, y3)] = tick(&projection, angle, p.tick_len);
+            let anchor = text_anchor(angle);
+            html! {
+                <>
+                <line x1={x1.to_string()} y1={y1.to_string()} x2={x2.to_string()} y2={y2.to_string()} class="tick" />
+                if let Some(l) = label {
+                    <text x={x3.to_string()} y={y3.to_string()} text-anchor={anchor} dominant-baseline="middle" class="text">{l}</text>
+                }
+                </>
+            }
+        });
+
+        let value_angle = clamped_angle(p.value);
+        let (needle_x, needle_y) = to_cartesian(p.x, p.y, projection.inner_radius, value_angle);
+        let hub_radius = p.arc_width * 0.25;
+
+        html! {
+            <g class={classes!("gauge", &p.name)}>
+                <path d={sector(projection.start_angle, projection.end_angle)} class="track" />
+                { for bands }
+                <path d={sector(projection.start_angle, value_angle)} class="value" />
+                { for ticks }
+                <line x1={p.x.to_string()} y1={p.y.to_string()} x2={needle_x.to_string()} y2={needle_y.to_string()} class="needle" />
+                <circle cx={p.x.to_string()} cy={p.y.to_string()} r={hub_radius.to_string()} class="hub" />
+                if let Some(l) = &p.labeller {
+                    <text x={p.x.to_string()} y={(p.y + p.radius * 0.5).to_string()} text-anchor="middle" dominant-baseline="middle" class="readout">{l(p.value)}</text>
+                }
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use super::*;
+    use crate::linear_axis_scale::LinearScale;
+
+    const PROJECTION: Projection = Projection {
+        x: 10.0,
+        y: 10.0,
+        inner_radius: 4.0,
+        radius: 6.0,
+        start_angle: -PI * 0.5,
+        end_angle: PI * 0.5,
+    };
+
+    #[test]
+    fn test_clamped_angle() {
+        assert_eq!(clamped_angle(&PROJECTION, NormalisedValue(0.5)), 0.0);
+        assert_eq!(clamped_angle(&PROJECTION, NormalisedValue(-1.0)), -PI * 0.5);
+        assert_eq!(clamped_angle(&PROJECTION, NormalisedValue(2.0)), PI * 0.5);
+    }
+
+    #[test]
+    fn test_tick() {
+        assert_eq!(
+            tick(&PROJECTION, 0.0, 1.0),
+            [(10.0, 4.0), (10.0, 3.0), (10.0, 2.0)]
+        );
+    }
+
+    #[test]
+    fn test_ticks_full_circle() {
+        let scale = LinearScale::new(0.0..100.0, 25.0);
+        let locations = |projection: &Projection| {
+            ticks(projection, &scale)
+                .into_iter()
+                .map(|t| t.location.0)
+                .collect::<Vec<_>>()
+        };
+        let full_circle = Projection {
+            start_angle: -PI,
+            end_angle: PI,
+            ..PROJECTION
+        };
+
+        assert_eq!(locations(&PROJECTION), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(locations(&full_circle), vec![0.0, 0.25, 0.5, 0.75]);
+    }
+}
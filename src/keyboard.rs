@@ -0,0 +1,368 @@
+/// A KeyboardNavigation makes the data points of one or more series focusable, so that a chart
+/// may be explored without a pointer. The plot area receives focus via the tab key, after which:
+///
+/// * left and right arrow keys move between the points of a series
+/// * home and end move to the first and last points of a series
+/// * up and down arrow keys move to the nearest point along the x axis of the previous or next
+///   series
+/// * enter or space selects the focused point
+/// * escape clears the focus
+///
+/// A marker is drawn at the focused point, and the point's values are announced to assistive
+/// technologies through a live region rendered by an [Announcer] such as [value_announcer].
+/// Points outside of the plot area's x bounds, and those of hidden series, are skipped.
+///
+/// The data and scales are expected to be the same ones provided to the chart's
+/// [Series](crate::series::Series).
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * keyboard-navigation - the navigation as a whole, which receives focus
+/// * area - the plot area, which may be outlined when focused
+/// * focus - the marker of the focused point, along with the series name
+/// * announcement - the visually hidden text announcing the focused point
+use std::{marker::PhantomData, rc::Rc};
+
+use yew::prelude::*;
+
+use crate::{
+    axis::Scale,
+    crosshair::Match,
+    series::{NamedData, Scalar},
+};
+
+const FOCUS_RADIUS: f32 = 5.0;
+
+/// Describes a closure that takes a focused point and produces the text announcing it
+pub trait Announcer<A: Scalar, B: Scalar>: Fn(&Match<A, B>) -> String {}
+
+impl<A: Scalar, B: Scalar, T: Fn(&Match<A, B>) -> String> Announcer<A, B> for T {}
+
+/// Announces a point's series name followed by its x and y values, formatted with the labellers
+/// provided.
+pub fn value_announcer<A: Scalar, B: Scalar>(
+    x_labeller: impl Fn(A) -> String,
+    y_labeller: impl Fn(B) -> String,
+) -> impl Announcer<A, B> {
+    move |m: &Match<A, B>| format!("{}: {}, {}", m.name, x_labeller(m.x), y_labeller(m.y))
+}
+
+/// The keys that navigate between points
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Key {
+    Previous,
+    Next,
+    First,
+    Last,
+    Up,
+    Down,
+    Select,
+    Clear,
+}
+
+impl Key {
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "ArrowLeft" => Some(Key::Previous),
+            "ArrowRight" => Some(Key::Next),
+            "Home" => Some(Key::First),
+            "End" => Some(Key::Last),
+            "ArrowUp" => Some(Key::Up),
+            "ArrowDown" => Some(Key::Down),
+            "Enter" | " " => Some(Key::Select),
+            "Escape" => Some(Key::Clear),
+            _ => None,
+        }
+    }
+}
+
+/// Determines the series and point indices focused after a key is pressed given the SVG x
+/// positions of each series' points. Points with positions that are not finite are skipped.
+/// Without a focused point, the first point of the first series with one is focused.
+fn step(positions: &[Vec<f32>], focus: Option<(usize, usize)>, key: Key) -> Option<(usize, usize)> {
+    let visible = |s: usize| {
+        positions[s]
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, x)| x.is_finite())
+    };
+    let first = |s: usize| visible(s).next().map(|(i, _)| (s, i));
+
+    let (s, i) = match focus {
+        Some(focus) => focus,
+        None if key == Key::Clear => return None,
+        None => return (0..positions.len()).find_map(first),
+    };
+    let x = positions[s][i];
+    let nearest = |t: usize| {
+        visible(t)
+            .min_by(|(_, a), (_, b)| (a - x).abs().total_cmp(&(b - x).abs()))
+            .map(|(j, _)| (t, j))
+    };
+
+    match key {
+        Key::Previous => visible(s)
+            .rfind(|(j, _)| *j < i)
+            .map(|(j, _)| (s, j))
+            .or(focus),
+        Key::Next => visible(s)
+            .find(|(j, _)| *j > i)
+            .map(|(j, _)| (s, j))
+            .or(focus),
+        Key::First => first(s).or(focus),
+        Key::Last => visible(s).next_back().map(|(j, _)| (s, j)).or(focus),
+        Key::Up => (0..s).rev().find_map(nearest).or(focus),
+        Key::Down => (s + 1..positions.len()).find_map(nearest).or(focus),
+        Key::Select => focus,
+        Key::Clear => None,
+    }
+}
+
+pub enum Msg {
+    Key(Key),
+    Focus,
+    Blur,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    /// A function that renders the text announcing the focused point
+    pub announcer: Rc<dyn Announcer<A, B>>,
+    /// The data of the series to be navigated
+    pub data: Rc<NamedData<A, B>>,
+    /// The SVG height of the plot area
+    pub height: f32,
+    /// The names of any series that are hidden, and so skipped
+    #[prop_or_default]
+    pub hidden: Vec<AttrValue>,
+    /// The scaling factor for data along the x axis
+    pub horizontal_scale: Rc<dyn Scale<Scalar = A>>,
+    /// An accessible label describing the chart
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    /// A callback to receive the focused point as it changes, and None when the focus is cleared
+    #[prop_or_default]
+    pub onfocuschange: Callback<Option<Match<A, B>>>,
+    /// A callback to receive the focused point when it is selected
+    #[prop_or_default]
+    pub onselect: Callback<Match<A, B>>,
+    /// The scaling factor for data along the y axis
+    pub vertical_scale: Rc<dyn Scale<Scalar = B>>,
+    /// The SVG width of the plot area
+    pub width: f32,
+    /// The start position
+    pub x: f32,
+    /// The start position
+    pub y: f32,
+}
+
+impl<A, B> PartialEq for Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.data, &other.data)
+            && self.height == other.height
+            && self.hidden == other.hidden
+            && self.label == other.label
+            && self.name == other.name
+            && self.onfocuschange == other.onfocuschange
+            && self.onselect == other.onselect
+            && self.width == other.width
+            && self.x == other.x
+            && self.y == other.y
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.announcer as *const _ as *const u8,
+                &*other.announcer as *const _ as *const u8,
+            )
+            && std::ptr::eq(
+                &*self.horizontal_scale as *const _ as *const u8,
+                &*other.horizontal_scale as *const _ as *const u8,
+            )
+            && std::ptr::eq(
+                &*self.vertical_scale as *const _ as *const u8,
+                &*other.vertical_scale as *const _ as *const u8,
+            )
+    }
+}
+
+pub struct KeyboardNavigation<A, B> {
+    focus: Option<(usize, usize)>,
+    focused: bool,
+    phantom: PhantomData<(A, B)>,
+}
+
+impl<A, B> KeyboardNavigation<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    // The SVG x positions of each series' points relative to the plot area, with points outside
+    // of the plot area's x bounds and those of hidden series being excluded.
+    fn positions(p: &Props<A, B>) -> Vec<Vec<f32>> {
+        let x_bounds = -0.1..=p.width + 0.1;
+        p.data
+            .iter()
+            .map(|(name, data)| {
+                let hidden = p.hidden.contains(name);
+                data.iter()
+                    .map(|(x, _, _)| {
+                        let x = p.horizontal_scale.normalise(*x).0 * p.width;
+                        if !hidden && x_bounds.contains(&x) {
+                            x
+                        } else {
+                            f32::NAN
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn point(p: &Props<A, B>, (s, i): (usize, usize)) -> Match<A, B> {
+        let (name, data) = &p.data[s];
+        let (x, y, _) = data[i];
+        Match {
+            name: name.clone(),
+            index: i,
+            x,
+            y,
+        }
+    }
+}
+
+impl<A: 'static, B: 'static> Component for KeyboardNavigation<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    type Message = Msg;
+
+    type Properties = Props<A, B>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        KeyboardNavigation {
+            focus: None,
+            focused: false,
+            phantom: PhantomData,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let p = ctx.props();
+        let focus = match msg {
+            Msg::Key(Key::Select) => {
+                if let Some(focus) = self.focus {
+                    p.onselect.emit(Self::point(p, focus));
+                }
+                return false;
+            }
+            Msg::Key(key) => step(&Self::positions(p), self.focus, key),
+            Msg::Focus => {
+                self.focused = true;
+                self.focus
+                    .or_else(|| step(&Self::positions(p), None, Key::First))
+            }
+            Msg::Blur => {
+                self.focused = false;
+                self.focus
+            }
+        };
+        if focus != self.focus {
+            self.focus = focus;
+            p.onfocuschange
+                .emit(focus.map(|focus| Self::point(p, focus)));
+        }
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        let p = ctx.props();
+        let positions = Self::positions(p);
+        if let Some((s, i)) = self.focus {
+            if !positions
+                .get(s)
+                .and_then(|series| series.get(i))
+                .is_some_and(|x| x.is_finite())
+            {
+                self.focus = None;
+                p.onfocuschange.emit(None);
+            }
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let p = ctx.props();
+
+        let onkeydown = ctx.link().batch_callback(|e: KeyboardEvent| {
+            let key = Key::from_key(&e.key());
+            if key.is_some() {
+                e.prevent_default();
+            }
+            key.map(Msg::Key)
+        });
+        let onfocus = ctx.link().callback(|_: FocusEvent| Msg::Focus);
+        let onblur = ctx.link().callback(|_: FocusEvent| Msg::Blur);
+
+        let focus = self.focus.map(|focus| (focus, Self::point(p, focus)));
+        let marker = focus.as_ref().filter(|_| self.focused).map(|(_, m)| {
+            let x = p.x + p.horizontal_scale.normalise(m.x).0 * p.width;
+            let y = p.y + p.height - p.vertical_scale.normalise(m.y).0 * p.height;
+            html! {
+                <circle cx={x.to_string()} cy={y.to_string()} r={FOCUS_RADIUS.to_string()}
+                    class={classes!("focus", &m.name)} pointer-events="none" />
+            }
+        });
+        let announcement = focus
+            .as_ref()
+            .map(|(_, m)| (p.announcer)(m))
+            .unwrap_or_default();
+
+        html! {
+            <g class={classes!("keyboard-navigation", &p.name)} tabindex="0" role="group"
+                aria-label={p.label.clone()} {onkeydown} {onfocus} {onblur}>
+                <rect x={p.x.to_string()} y={p.y.to_string()} width={p.width.to_string()} height={p.height.to_string()}
+                    fill="none" pointer-events="none" class="area" />
+                { for marker }
+                <text x={p.x.to_string()} y={p.y.to_string()} opacity="0" pointer-events="none"
+                    role="status" aria-live="polite" class="announcement">{announcement}</text>
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        let positions = vec![vec![0.0, 10.0, f32::NAN, 30.0], vec![], vec![5.0, 25.0]];
+
+        assert_eq!(step(&positions, None, Key::Next), Some((0, 0)));
+        assert_eq!(step(&positions, None, Key::Clear), None);
+        assert_eq!(step(&positions, Some((0, 1)), Key::Next), Some((0, 3)));
+        assert_eq!(step(&positions, Some((0, 3)), Key::Next), Some((0, 3)));
+        assert_eq!(step(&positions, Some((0, 3)), Key::Previous), Some((0, 1)));
+        assert_eq!(step(&positions, Some((0, 0)), Key::Previous), Some((0, 0)));
+        assert_eq!(step(&positions, Some((0, 1)), Key::Last), Some((0, 3)));
+        assert_eq!(step(&positions, Some((0, 3)), Key::First), Some((0, 0)));
+        assert_eq!(step(&positions, Some((0, 3)), Key::Down), Some((2, 1)));
+        assert_eq!(step(&positions, Some((2, 0)), Key::Up), Some((0, 0)));
+        assert_eq!(step(&positions, Some((2, 0)), Key::Down), Some((2, 0)));
+        assert_eq!(step(&positions, Some((2, 0)), Key::Clear), None);
+    }
+}
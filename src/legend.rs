@@ -0,0 +1,155 @@
+/// A Legend lists the series of a chart, each with a swatch that may be styled with its series
+/// name. Clicking an entry toggles the visibility of its series.
+///
+/// The component does not own the visibility of series. Instead, the names of the hidden series
+/// are reported through a callback whenever an entry is toggled, so that they may be provided to
+/// the hidden property of each [Series](crate::series::Series) and then back to this component.
+/// Hidden series keep their layout so that the chart does not shift. The y axis may optionally be
+/// rescaled to the remaining visible data using [visible_range].
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * legend - the legend as a whole
+/// * entry - an entry, along with its series name, and hidden when its series is hidden
+/// * swatch - the swatch of an entry, along with its series name
+/// * label - the text of an entry
+use std::{ops::Range, rc::Rc};
+
+use yew::prelude::*;
+
+use crate::series::{NamedData, Scalar};
+
+const LABEL_OFFSET: f32 = 4.0;
+
+/// Describes an entry of the legend
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    /// The name of the series, as given to the series for CSS selection
+    pub name: AttrValue,
+    /// The text to be displayed for the series
+    pub label: AttrValue,
+}
+
+/// Toggles whether a series is within a list of hidden series.
+fn toggle(hidden: &[AttrValue], name: &AttrValue) -> Vec<AttrValue> {
+    if hidden.contains(name) {
+        hidden.iter().filter(|n| *n != name).cloned().collect()
+    } else {
+        hidden.iter().chain(Some(name)).cloned().collect()
+    }
+}
+
+/// The range of y values of the series that are not hidden, or None if there are no such values
+pub fn visible_range<A, B>(data: &NamedData<A, B>, hidden: &[AttrValue]) -> Option<Range<B>>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    data.iter()
+        .filter(|(name, _)| !hidden.contains(name))
+        .flat_map(|(_, data)| data.iter().map(|(_, y, _)| *y))
+        .fold(None, |range: Option<Range<B>>, y| match range {
+            Some(r) if y < r.start => Some(y..r.end),
+            Some(r) if y > r.end => Some(r.start..y),
+            Some(r) => Some(r),
+            None => Some(y..y),
+        })
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct Props {
+    /// The entries of the legend, in order
+    pub entries: Rc<Vec<Entry>>,
+    /// The names of the series that are hidden
+    #[prop_or_default]
+    pub hidden: Vec<AttrValue>,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    /// A callback to receive the names of the hidden series when an entry is toggled
+    pub onvisibilitychange: Callback<Vec<AttrValue>>,
+    /// The SVG distance between entries
+    #[prop_or(16.0)]
+    pub spacing: f32,
+    /// The SVG size of each swatch
+    #[prop_or(10.0)]
+    pub swatch_size: f32,
+    /// The start position
+    pub x: f32,
+    /// The start position
+    pub y: f32,
+}
+
+pub struct Legend;
+
+impl Component for Legend {
+    type Message = ();
+
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Legend
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let p = ctx.props();
+
+        let entries = p.entries.iter().enumerate().map(|(i, entry)| {
+            let y = p.y + i as f32 * p.spacing;
+            let hidden = p.hidden.contains(&entry.name);
+            let onclick = {
+                let cb = p.onvisibilitychange.clone();
+                let hidden = p.hidden.clone();
+                let name = entry.name.clone();
+                move |_: MouseEvent| cb.emit(toggle(&hidden, &name))
+            };
+            html! {
+                <g class={classes!("entry", &entry.name, hidden.then_some("hidden"))} cursor="pointer" {onclick}>
+                    <rect x={p.x.to_string()} y={y.to_string()} width={p.swatch_size.to_string()} height={p.swatch_size.to_string()}
+                        class={classes!("swatch", &entry.name)} />
+                    <text x={(p.x + p.swatch_size + LABEL_OFFSET).to_string()} y={(y + p.swatch_size * 0.5).to_string()}
+                        dominant-baseline="middle" class="label">{&entry.label}</text>
+                </g>
+            }
+        });
+
+        html! {
+            <g class={classes!("legend", &p.name)}>
+                { for entries }
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle() {
+        let (a, b) = (AttrValue::from("a"), AttrValue::from("b"));
+
+        assert_eq!(toggle(&[], &a), vec![a.clone()]);
+        assert_eq!(toggle(&["a".into()], &b), vec![a.clone(), b.clone()]);
+        assert_eq!(toggle(&[a.clone(), b.clone()], &a), vec![b]);
+    }
+
+    #[test]
+    fn test_visible_range() {
+        let data: NamedData<f32, f32> = vec![
+            (
+                AttrValue::from("a"),
+                Rc::new(vec![(0.0, 1.0, None), (1.0, 5.0, None)]),
+            ),
+            (
+                AttrValue::from("b"),
+                Rc::new(vec![(0.0, -2.0, None), (1.0, 10.0, None)]),
+            ),
+        ];
+
+        assert_eq!(visible_range(&data, &[]), Some(-2.0..10.0));
+        assert_eq!(visible_range(&data, &["b".into()]), Some(1.0..5.0));
+        assert_eq!(visible_range(&data, &["a".into(), "b".into()]), None);
+    }
+}
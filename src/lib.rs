@@ -2,6 +2,34 @@
 /// By leveraging these SVG-based components many types of charts can be formed
 /// with a great deal of flexibility.
 pub mod axis;
+pub mod brush;
+pub mod bubble_series;
+pub mod callout;
+pub mod colour_bar;
+pub mod colour_scale;
+pub mod crosshair;
+pub mod cursor;
+pub mod event_markers;
+pub mod gauge;
+pub mod keyboard;
+pub mod legend;
 pub mod linear_axis_scale;
+pub mod marker;
+pub mod navigator;
+pub mod pie;
+mod polar;
+pub mod polar_axis;
+pub mod polar_series;
+pub mod radar;
+pub mod reference_line;
+pub mod region;
 pub mod series;
+pub mod sparkline;
+pub mod sqrt_axis_scale;
 pub mod time_axis_scale;
+pub mod timeline;
+#[cfg(feature = "custom-tooltip")]
+pub mod tooltip;
+pub mod vector_series;
+pub mod waterfall_series;
+pub mod zoom;
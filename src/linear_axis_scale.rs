@@ -2,7 +2,7 @@
 /// A step is also expressed and indicates the interval to be used for each tick on the axis.
 use std::{ops::Range, rc::Rc};
 
-use crate::axis::{NormalisedValue, Scale, Tick};
+use crate::axis::{InvertibleScale, NormalisedValue, Scale, Tick};
 
 /// An axis labeller is a closure that produces a string given a value within the axis scale
 pub trait Labeller: Fn(f32) -> String {}
@@ -70,6 +70,12 @@ impl Scale for LinearScale {
     }
 }
 
+impl InvertibleScale for LinearScale {
+    fn denormalise(&self, value: NormalisedValue) -> Self::Scalar {
+        value.0 / self.scale + self.range.start
+    }
+}
+
 struct LinearScaleInclusiveIter {
     pub from: f32,
     pub to: f32,
@@ -133,6 +139,7 @@ mod tests {
         );
 
         assert_eq!(scale.normalise(50.0), NormalisedValue(0.5));
+        assert_eq!(scale.denormalise(NormalisedValue(0.5)), 50.0);
     }
 
     #[test]
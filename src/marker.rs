@@ -0,0 +1,182 @@
+/// A Marker is a shape drawn at a data point, typically for scatter plots. Markers may be
+/// selected for a [Series](crate::series::Series) as a whole, and their size and colour may
+/// optionally be derived from each point's data values through scales.
+///
+/// The following styling properties are available:
+///
+/// * marker - the marker shape, along with the name of the shape e.g. circle, square
+use std::{ops::Range, rc::Rc};
+
+use yew::prelude::*;
+
+use crate::{
+    axis::Scale,
+    colour_scale::{Colour, ColourScale},
+    series::{Labeller, Scalar},
+};
+
+/// The SVG size of a marker when no other size is specified
+pub const DEFAULT_MARKER_SIZE: f32 = 3.0;
+
+/// Describes the shape of a marker
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Marker {
+    Circle,
+    Square,
+    Triangle,
+    Diamond,
+    Cross,
+    Plus,
+    Star,
+}
+
+impl Marker {
+    fn class(&self) -> &'static str {
+        match self {
+            Marker::Circle => "circle",
+            Marker::Square => "square",
+            Marker::Triangle => "triangle",
+            Marker::Diamond => "diamond",
+            Marker::Cross => "cross",
+            Marker::Plus => "plus",
+            Marker::Star => "star",
+        }
+    }
+
+    /// The vertices of the marker's polygon when centred on the origin, for those
+    /// markers that are rendered as polygons
+    fn vertices(&self, size: f32) -> Vec<(f32, f32)> {
+        let r = size * 0.5;
+        match self {
+            Marker::Triangle => vec![(0.0, -r), (r * 0.866, r * 0.5), (-r * 0.866, r * 0.5)],
+            Marker::Diamond => vec![(0.0, -r), (r, 0.0), (0.0, r), (-r, 0.0)],
+            Marker::Star => (0..10)
+                .map(|i| {
+                    let angle = (i as f32 * 36.0).to_radians();
+                    let distance = if i % 2 == 0 { r } else { r * 0.4 };
+                    (distance * angle.sin(), -distance * angle.cos())
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Renders the marker centred on a position with a given SVG size and an optional colour.
+    /// If no colour is given then the marker is expected to be coloured via CSS.
+    pub fn render(&self, x: f32, y: f32, size: f32, colour: Option<Colour>) -> Html {
+        let r = size * 0.5;
+        let class = classes!("marker", self.class());
+        let colour = colour.map(|c| c.to_string());
+        match self {
+            Marker::Circle => html! {
+                <circle cx={x.to_string()} cy={y.to_string()} r={r.to_string()}
+                    fill={colour.clone()} stroke={colour} class={class} />
+            },
+            Marker::Square => html! {
+                <rect x={(x - r).to_string()} y={(y - r).to_string()}
+                    width={size.to_string()} height={size.to_string()}
+                    fill={colour.clone()} stroke={colour} class={class} />
+            },
+            Marker::Cross => {
+                let d = r * 0.707;
+                html! {
+                    <path d={format!("M{},{} L{},{} M{},{} L{},{}", x - d, y - d, x + d, y + d, x + d, y - d, x - d, y + d)}
+                        fill="none" stroke={colour} class={class} />
+                }
+            }
+            Marker::Plus => html! {
+                <path d={format!("M{},{} L{},{} M{},{} L{},{}", x - r, y, x + r, y, x, y - r, x, y + r)}
+                    fill="none" stroke={colour} class={class} />
+            },
+            Marker::Triangle | Marker::Diamond | Marker::Star => {
+                let points = self
+                    .vertices(size)
+                    .iter()
+                    .map(|(vx, vy)| format!("{},{} ", x + vx, y + vy))
+                    .collect::<Vec<_>>()
+                    .concat();
+                html! {
+                    <polygon points={points} fill={colour.clone()} stroke={colour} class={class} />
+                }
+            }
+        }
+    }
+}
+
+/// A labeller that draws a marker of a given SVG size.
+pub fn marker_label(marker: Marker, size: f32) -> impl Labeller {
+    move |x: f32, y: f32| marker.render(x, y, size, None)
+}
+
+/// Describes a closure that takes data values (x, y) and produces the SVG size of a marker
+pub trait Sizer<A: Scalar, B: Scalar>: Fn(A, B) -> f32 {}
+
+impl<A: Scalar, B: Scalar, T: Fn(A, B) -> f32> Sizer<A, B> for T {}
+
+/// Describes a closure that takes data values (x, y) and produces the colour of a marker
+pub trait Colourer<A: Scalar, B: Scalar>: Fn(A, B) -> Colour {}
+
+impl<A: Scalar, B: Scalar, T: Fn(A, B) -> Colour> Colourer<A, B> for T {}
+
+fn scaled_size(normalised: f32, sizes: &Range<f32>) -> f32 {
+    sizes.start + normalised.clamp(0.0, 1.0) * (sizes.end - sizes.start)
+}
+
+/// Sizes markers given their x value, with the minimum of the scale being
+/// the start of the range of sizes and the maximum being the end
+pub fn size_by_x<A: Scalar, B: Scalar>(
+    scale: Rc<dyn Scale<Scalar = A>>,
+    sizes: Range<f32>,
+) -> impl Sizer<A, B> {
+    move |x, _| scaled_size(scale.normalise(x).0, &sizes)
+}
+
+/// Sizes markers given their y value, with the minimum of the scale being
+/// the start of the range of sizes and the maximum being the end
+pub fn size_by_y<A: Scalar, B: Scalar>(
+    scale: Rc<dyn Scale<Scalar = B>>,
+    sizes: Range<f32>,
+) -> impl Sizer<A, B> {
+    move |_, y| scaled_size(scale.normalise(y).0, &sizes)
+}
+
+/// Colours markers given their x value
+pub fn colour_by_x<A: Scalar, B: Scalar>(
+    colour_scale: Rc<dyn ColourScale<Scalar = A>>,
+) -> impl Colourer<A, B> {
+    move |x, _| colour_scale.colour(x)
+}
+
+/// Colours markers given their y value
+pub fn colour_by_y<A: Scalar, B: Scalar>(
+    colour_scale: Rc<dyn ColourScale<Scalar = B>>,
+) -> impl Colourer<A, B> {
+    move |_, y| colour_scale.colour(y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::linear_axis_scale::LinearScale;
+
+    #[test]
+    fn test_vertices() {
+        assert_eq!(
+            Marker::Diamond.vertices(2.0),
+            vec![(0.0, -1.0), (1.0, 0.0), (0.0, 1.0), (-1.0, 0.0)]
+        );
+        assert_eq!(Marker::Star.vertices(2.0).len(), 10);
+        assert_eq!(Marker::Star.vertices(2.0)[0], (0.0, -1.0));
+        assert!(Marker::Circle.vertices(2.0).is_empty());
+    }
+
+    #[test]
+    fn test_size_by_y() {
+        let sizer = size_by_y::<f32, f32>(Rc::new(LinearScale::new(0.0..100.0, 10.0)), 2.0..10.0);
+
+        assert_eq!(sizer(0.0, 0.0), 2.0);
+        assert_eq!(sizer(0.0, 50.0), 6.0);
+        assert_eq!(sizer(0.0, 200.0), 10.0);
+    }
+}
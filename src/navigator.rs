@@ -0,0 +1,347 @@
+/// A Navigator is a small overview of a time series, typically placed beneath the main chart as
+/// with stock charts. The whole of the data is drawn using a [Series](crate::series::Series)
+/// and a bottom [Axis](crate::axis::Axis) scaled by a [TimeScale] over the navigator's extent,
+/// and a window is drawn over it to convey the main chart's x domain. The area outside of the
+/// window is masked.
+///
+/// The window may be dragged to pan it, or resized by dragging its handles. Pressing outside of
+/// the window centres it on the pointer, after which it may continue to be dragged.
+///
+/// The component does not own the window. Instead, each change is reported through a callback
+/// so that the main chart's scales may be recreated with the new domain, which is then provided
+/// back to this component. The main chart may also change the window, for example via a
+/// [ZoomPan](crate::zoom::ZoomPan), and the navigator will follow it.
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+/// The name is also given to the navigator's series and axis.
+///
+/// The following styling properties are available:
+///
+/// * navigator - the navigator as a whole
+/// * background - the transparent rectangle behind the window receiving pointer events
+/// * mask - the area outside of the window, along with start or end
+/// * window - the window
+/// * handle - a handle for resizing the window, along with start or end
+use std::{ops::Range, rc::Rc};
+
+use chrono::{DateTime, Duration, Utc};
+use web_sys::Element;
+use yew::prelude::*;
+
+use crate::{
+    axis::{Axis, Orientation, Scale},
+    series::{Data, Series, Type},
+    time_axis_scale::TimeScale,
+    zoom::clamp,
+};
+
+/// The part of the navigator being dragged
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Part {
+    Background,
+    Window,
+    Start,
+    End,
+}
+
+/// Determines the window resulting from dragging a part of it from one value to another.
+fn adjust(
+    part: Part,
+    window: (f64, f64),
+    extent: (f64, f64),
+    from: f64,
+    to: f64,
+    min_span: f64,
+) -> (f64, f64) {
+    match part {
+        Part::Background | Part::Window => clamp(
+            (window.0 + to - from, window.1 + to - from),
+            extent,
+            min_span,
+            0.5,
+        ),
+        Part::Start => (to.min(window.1 - min_span).max(extent.0), window.1),
+        Part::End => (window.0, to.max(window.0 + min_span).min(extent.1)),
+    }
+}
+
+// The part being dragged, the value at the pointer and the window at the start of a drag
+struct Gesture {
+    part: Part,
+    origin: f64,
+    window: (f64, f64),
+}
+
+pub enum Msg {
+    Start(Part, f64),
+    Move(f64),
+    End,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    /// The data to be drawn, typically the whole of the main chart's data
+    pub data: Rc<Data<i64, f32>>,
+    /// The time range covered by the navigator
+    pub extent: Range<DateTime<Utc>>,
+    /// The SVG width of the window's handles
+    #[prop_or(8.0)]
+    pub handle_width: f32,
+    /// The SVG height of the plot area
+    pub height: f32,
+    /// The minimum span of the window in milliseconds. If None then the window is not limited.
+    #[prop_or_default]
+    pub min_span: Option<i64>,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    /// A callback to receive the new window as it changes
+    pub onwindowchange: Callback<Range<i64>>,
+    /// How the data is drawn
+    #[prop_or(Type::Line)]
+    pub series_type: Type,
+    /// The tick interval of the navigator's axis
+    pub step: Duration,
+    /// The length of the axis ticks
+    #[prop_or(5.0)]
+    pub tick_len: f32,
+    /// The scaling factor for data along the y axis
+    pub vertical_scale: Rc<dyn Scale<Scalar = f32>>,
+    /// The SVG width of the plot area
+    pub width: f32,
+    /// The current window as a range of timestamps in milliseconds
+    pub window: Range<i64>,
+    /// The start position
+    pub x: f32,
+    /// The start position
+    pub y: f32,
+}
+
+impl PartialEq for Props {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.data, &other.data)
+            && self.extent == other.extent
+            && self.handle_width == other.handle_width
+            && self.height == other.height
+            && self.min_span == other.min_span
+            && self.name == other.name
+            && self.onwindowchange == other.onwindowchange
+            && self.series_type == other.series_type
+            && self.step == other.step
+            && self.tick_len == other.tick_len
+            && self.width == other.width
+            && self.window == other.window
+            && self.x == other.x
+            && self.y == other.y
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.vertical_scale as *const _ as *const u8,
+                &*other.vertical_scale as *const _ as *const u8,
+            )
+    }
+}
+
+pub struct Navigator {
+    background: NodeRef,
+    gesture: Option<Gesture>,
+    scale: Rc<dyn Scale<Scalar = i64>>,
+}
+
+impl Navigator {
+    fn scale(p: &Props) -> Rc<dyn Scale<Scalar = i64>> {
+        Rc::new(TimeScale::new(p.extent.clone(), p.step))
+    }
+
+    fn extent(p: &Props) -> (f64, f64) {
+        (
+            p.extent.start.timestamp_millis() as f64,
+            p.extent.end.timestamp_millis() as f64,
+        )
+    }
+
+    fn emit(p: &Props, window: (f64, f64)) {
+        let window = window.0.round() as i64..window.1.round() as i64;
+        if window != p.window {
+            p.onwindowchange.emit(window);
+        }
+    }
+}
+
+impl Component for Navigator {
+    type Message = Msg;
+
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Navigator {
+            background: NodeRef::default(),
+            gesture: None,
+            scale: Self::scale(ctx.props()),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let p = ctx.props();
+        let extent = Self::extent(p);
+        let min_span = p.min_span.unwrap_or_default() as f64;
+        let window = (p.window.start as f64, p.window.end as f64);
+        match msg {
+            Msg::Start(part, value) => {
+                let window = match part {
+                    Part::Background => {
+                        let window = adjust(
+                            part,
+                            window,
+                            extent,
+                            (window.0 + window.1) * 0.5,
+                            value,
+                            min_span,
+                        );
+                        Self::emit(p, window);
+                        window
+                    }
+                    _ => window,
+                };
+                self.gesture = Some(Gesture {
+                    part,
+                    origin: value,
+                    window,
+                });
+            }
+            Msg::Move(value) => {
+                if let Some(g) = &self.gesture {
+                    Self::emit(
+                        p,
+                        adjust(g.part, g.window, extent, g.origin, value, min_span),
+                    );
+                }
+            }
+            Msg::End => self.gesture = None,
+        }
+        false
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let p = ctx.props();
+        if p.extent != old_props.extent || p.step != old_props.step {
+            self.scale = Self::scale(p);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let p = ctx.props();
+
+        let background = self.background.clone();
+        let extent = Self::extent(p);
+        let value = move |e: &PointerEvent| {
+            background.cast::<Element>().map(|b| {
+                let rect = b.get_bounding_client_rect();
+                let fraction = if rect.width() > 0.0 {
+                    ((e.client_x() as f64 - rect.left()) / rect.width()).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                extent.0 + fraction * (extent.1 - extent.0)
+            })
+        };
+        let onpointerdown = |part: Part| {
+            let value = value.clone();
+            ctx.link().batch_callback(move |e: PointerEvent| {
+                let _ = e
+                    .target_unchecked_into::<Element>()
+                    .set_pointer_capture(e.pointer_id());
+                value(&e).map(|v| Msg::Start(part, v))
+            })
+        };
+        let onpointermove = {
+            let value = value.clone();
+            ctx.link()
+                .batch_callback(move |e: PointerEvent| value(&e).map(Msg::Move))
+        };
+        let onpointerup = ctx.link().callback(|_: PointerEvent| Msg::End);
+
+        let position = |t: i64| p.x + self.scale.normalise(t).0.clamp(0.0, 1.0) * p.width;
+        let start = position(p.window.start);
+        let end = position(p.window.end);
+        let half = p.handle_width * 0.5;
+
+        let handles = vec![(Part::Start, "start", start), (Part::End, "end", end)]
+            .into_iter()
+            .map(|(part, class, x)| {
+                html! {
+                    <rect x={(x - half).to_string()} y={p.y.to_string()} width={p.handle_width.to_string()} height={p.height.to_string()}
+                        class={classes!("handle", class)} cursor="ew-resize"
+                        onpointerdown={onpointerdown(part)}
+                        onpointermove={onpointermove.clone()} onpointerup={onpointerup.clone()} />
+                }
+            });
+
+        html! {
+            <g class={classes!("navigator", &p.name)} style="touch-action: none">
+                <Series<i64, f32>
+                    series_type={p.series_type.clone()}
+                    name={p.name.clone()}
+                    data={Rc::clone(&p.data)}
+                    horizontal_scale={Rc::clone(&self.scale)}
+                    vertical_scale={Rc::clone(&p.vertical_scale)}
+                    x={p.x} y={p.y} width={p.width} height={p.height} />
+                <Axis<i64>
+                    name={p.name.clone()}
+                    orientation={Orientation::Bottom}
+                    scale={Rc::clone(&self.scale)}
+                    x1={p.x} y1={p.y + p.height} xy2={p.x + p.width}
+                    tick_len={p.tick_len} />
+                <rect ref={self.background.clone()} x={p.x.to_string()} y={p.y.to_string()} width={p.width.to_string()} height={p.height.to_string()}
+                    fill="transparent" class="background"
+                    onpointerdown={onpointerdown(Part::Background)}
+                    onpointermove={onpointermove.clone()} onpointerup={onpointerup.clone()} />
+                <rect x={p.x.to_string()} y={p.y.to_string()} width={(start - p.x).to_string()} height={p.height.to_string()}
+                    class={classes!("mask", "start")} pointer-events="none" />
+                <rect x={end.to_string()} y={p.y.to_string()} width={(p.x + p.width - end).to_string()} height={p.height.to_string()}
+                    class={classes!("mask", "end")} pointer-events="none" />
+                <rect x={start.to_string()} y={p.y.to_string()} width={(end - start).to_string()} height={p.height.to_string()}
+                    class="window" cursor="move"
+                    onpointerdown={onpointerdown(Part::Window)}
+                    onpointermove={onpointermove.clone()} onpointerup={onpointerup.clone()} />
+                { for handles }
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adjust() {
+        let extent = (0.0, 100.0);
+        let window = (20.0, 40.0);
+
+        assert_eq!(
+            adjust(Part::Window, window, extent, 30.0, 40.0, 0.0),
+            (30.0, 50.0)
+        );
+        assert_eq!(
+            adjust(Part::Window, window, extent, 30.0, 0.0, 0.0),
+            (0.0, 20.0)
+        );
+        assert_eq!(
+            adjust(Part::Start, window, extent, 20.0, 10.0, 0.0),
+            (10.0, 40.0)
+        );
+        assert_eq!(
+            adjust(Part::Start, window, extent, 20.0, 60.0, 5.0),
+            (35.0, 40.0)
+        );
+        assert_eq!(
+            adjust(Part::End, window, extent, 40.0, 120.0, 0.0),
+            (20.0, 100.0)
+        );
+        assert_eq!(
+            adjust(Part::End, window, extent, 40.0, 0.0, 5.0),
+            (20.0, 25.0)
+        );
+    }
+}
@@ -0,0 +1,249 @@
+/// A Pie renders labelled values as slices of a circle, each slice's angle being proportional
+/// to its share of the total. Setting an inner radius renders the pie as a donut.
+///
+/// Angles are expressed in degrees, clockwise from 12 o'clock.
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * pie - the pie as a whole
+/// * slice - each slice, along with slice-0, slice-1 etc. given the slice's position in the data
+/// * leader - the line joining a slice with its label
+/// * label - the text of a slice's label
+use std::{f32::consts::PI, rc::Rc};
+
+use yew::prelude::*;
+
+use crate::polar::{arc_path, to_cartesian};
+#[cfg(feature = "custom-tooltip")]
+use crate::series::TooltipCallback;
+
+/// Describes a closure that takes a slice's label, value and fraction of the total, and
+/// produces a tooltip string for the slice.
+pub trait Tooltipper: Fn(&str, f32, f32) -> String {}
+
+impl<T: Fn(&str, f32, f32) -> String> Tooltipper for T {}
+
+/// Describes the labelled values of a pie
+pub type Data = Vec<(String, f32)>;
+
+/// Basic tooltip that outputs a slice's label and percentage of the total
+pub fn percentage_tooltip() -> impl Tooltipper {
+    |label: &str, _, fraction: f32| format!("{label}: {:.0}%", fraction * 100.0)
+}
+
+/// Computes the start and end angles in radians of each slice given the values, the starting
+/// angle of the first slice and the padding between slices. Negative values are regarded as
+/// zero. Slices narrower than their padding are collapsed to their mid angle.
+fn slice_angles(data: &Data, start_angle: f32, pad_angle: f32) -> Vec<(f32, f32)> {
+    let total = data.iter().map(|(_, v)| v.max(0.0)).sum::<f32>();
+    let scale = if total > 0.0 { 2.0 * PI / total } else { 0.0 };
+    let half_pad = pad_angle.to_radians() * 0.5;
+
+    let mut angle = start_angle.to_radians();
+    data.iter()
+        .map(|(_, value)| {
+            let start = angle;
+            angle += value.max(0.0) * scale;
+            let end = angle;
+            if end - start > half_pad * 2.0 {
+                (start + half_pad, end - half_pad)
+            } else {
+                let mid = (start + end) * 0.5;
+                (mid, mid)
+            }
+        })
+        .collect()
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    /// The labelled values to be rendered as slices
+    pub data: Rc<Data>,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    /// The centre position
+    pub x: f32,
+    /// The centre position
+    pub y: f32,
+    /// The SVG radius of the pie
+    pub radius: f32,
+    /// The SVG radius of the hole in the middle, rendering a donut. Zero renders a pie.
+    #[prop_or_default]
+    pub inner_radius: f32,
+    /// The angle in degrees at which the first slice starts
+    #[prop_or_default]
+    pub start_angle: f32,
+    /// The angle in degrees to leave between slices
+    #[prop_or_default]
+    pub pad_angle: f32,
+    /// When set, slices are labelled with text positioned this far beyond the radius
+    /// and joined to the slice with a leader line
+    #[prop_or_default]
+    pub label_offset: Option<f32>,
+    #[cfg(feature = "custom-tooltip")]
+    /// A callback to receive mouseover events along with tooltipper function text results. Requires
+    /// the custom-tooltip feature.
+    #[prop_or_else(|| Rc::new(Callback::noop()))]
+    pub onmouseover: Rc<TooltipCallback>,
+    /// An optional function that renders a string to be used for tooltips
+    #[prop_or_default]
+    pub tooltipper: Option<Rc<dyn Tooltipper>>,
+}
+
+impl Props {
+    #[cfg(feature = "custom-tooltip")]
+    fn is_onmouseover_eq(&self, other: &Self) -> bool {
+        self.onmouseover == other.onmouseover
+    }
+    #[cfg(not(feature = "custom-tooltip"))]
+    fn is_onmouseover_eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl PartialEq for Props {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.data, &other.data)
+            && self.name == other.name
+            && self.x == other.x
+            && self.y == other.y
+            && self.radius == other.radius
+            && self.inner_radius == other.inner_radius
+            && self.start_angle == other.start_angle
+            && self.pad_angle == other.pad_angle
+            && self.label_offset == other.label_offset
+            && self.is_onmouseover_eq(other)
+            && match (self.tooltipper.as_ref(), other.tooltipper.as_ref()) {
+                (Some(left), Some(right)) => std::ptr::eq(
+                    left as *const _ as *const u8,
+                    right as *const _ as *const u8,
+                ),
+                _ => false,
+            }
+    }
+}
+
+pub struct Pie;
+
+impl Component for Pie {
+    type Message = ();
+
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Pie
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        #[cfg(feature = "custom-tooltip")]
+        fn onmouseover(cb: &Rc<TooltipCallback>, title: String) -> impl Fn(MouseEvent) {
+            let cb = Rc::clone(cb);
+            move |e| {
+                (*cb).emit((e, title.clone()));
+            }
+        }
+
+        let p = ctx.props();
+
+        let total = p.data.iter().map(|(_, v)| v.max(0.0)).sum::<f32>();
+        let angles = slice_angles(&p.data, p.start_angle, p.pad_angle);
+
+        let slices = p.data.iter().zip(angles).enumerate().map(|(i, ((label, value), (start, end)))| {
+            let fraction = if total > 0.0 { value.max(0.0) / total } else { 0.0 };
+            let title = p.tooltipper.as_ref().map(|tt| tt(label, *value, fraction));
+            let d = arc_path(p.x, p.y, p.inner_radius, p.radius, start, end);
+            let class = classes!("slice", format!("slice-{i}"));
+
+            #[cfg(feature = "custom-tooltip")]
+            let slice = html! {
+                <path d={d} class={class}
+                    onmouseover={onmouseover(&p.onmouseover, title.unwrap_or_default())} />
+            };
+            #[cfg(not(feature = "custom-tooltip"))]
+            let slice = html! {
+                <path d={d} class={class}>
+                    if let Some(t) = title {
+                        <title>{t}</title>
+                    }
+                </path>
+            };
+
+            let label = p.label_offset.filter(|_| end > start).map(|offset| {
+                let mid_angle = (start + end) * 0.5;
+                let (x1, y1) = to_cartesian(p.x, p.y, p.radius, mid_angle);
+                let (x2, y2) = to_cartesian(p.x, p.y, p.radius + offset, mid_angle);
+                let (x3, anchor) = if mid_angle.sin() >= 0.0 {
+                    (x2 + offset * 0.5, "start")
+                } else {
+                    (x2 - offset * 0.5, "end")
+                };
+                html! {
+                    <>
+                    <polyline points={format!("{x1},{y1} {x2},{y2} {x3},{y2}")} fill="none" class="leader" />
+                    <text x={x3.to_string()} y={y2.to_string()} text-anchor={anchor} dominant-baseline="middle" class="label">{label}</text>
+                    </>
+                }
+            });
+
+            html! {
+                <>
+                {slice}
+                {for label}
+                </>
+            }
+        });
+
+        html! {
+            <g class={classes!("pie", &p.name)}>
+                { for slices }
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slice_angles() {
+        let data = vec![
+            ("a".to_string(), 1.0),
+            ("b".to_string(), 3.0),
+            ("c".to_string(), -1.0),
+        ];
+
+        assert_eq!(
+            slice_angles(&data, 0.0, 0.0),
+            vec![(0.0, PI * 0.5), (PI * 0.5, PI * 2.0), (PI * 2.0, PI * 2.0)]
+        );
+
+        assert_eq!(
+            slice_angles(&data, 90.0, 0.0),
+            vec![(PI * 0.5, PI), (PI, PI * 2.5), (PI * 2.5, PI * 2.5)]
+        );
+    }
+
+    #[test]
+    fn test_padded_slice_angles() {
+        let data = vec![("a".to_string(), 1.0), ("b".to_string(), 1.0)];
+
+        assert_eq!(
+            slice_angles(&data, 0.0, 180.0),
+            vec![(PI * 0.5, PI * 0.5), (PI * 1.5, PI * 1.5)]
+        );
+
+        let (start, end) = slice_angles(&data, 0.0, 2.0)[0];
+        assert!((start - 1f32.to_radians()).abs() < 1e-6);
+        assert!((end - 179f32.to_radians()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_empty_slice_angles() {
+        let data = vec![("a".to_string(), 0.0)];
+
+        assert_eq!(slice_angles(&data, 0.0, 0.0), vec![(0.0, 0.0)]);
+    }
+}
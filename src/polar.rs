@@ -0,0 +1,164 @@
+/// Geometry shared by components that are rendered in polar space. Angles are in radians,
+/// clockwise from 12 o'clock.
+use crate::axis::NormalisedValue;
+
+/// Converts an angle and a distance from a centre into SVG coordinates.
+pub(crate) fn to_cartesian(cx: f32, cy: f32, radius: f32, angle: f32) -> (f32, f32) {
+    (cx + radius * angle.sin(), cy - radius * angle.cos())
+}
+
+// The commands that continue a path along an arc from the start angle to the end angle. The
+// arc is drawn as two halves so that a full circle can also be described.
+fn arc_to(cx: f32, cy: f32, radius: f32, start_angle: f32, end_angle: f32) -> String {
+    let mid_angle = (start_angle + end_angle) * 0.5;
+    let (x1, y1) = to_cartesian(cx, cy, radius, mid_angle);
+    let (x2, y2) = to_cartesian(cx, cy, radius, end_angle);
+    let sweep = if end_angle >= start_angle { 1 } else { 0 };
+    format!("A{radius},{radius} 0 0 {sweep} {x1},{y1} A{radius},{radius} 0 0 {sweep} {x2},{y2}")
+}
+
+/// Whether an angular tick is drawn. On a full circle the maximum of the scale coincides with its
+/// minimum and so is not drawn.
+pub(crate) fn is_tick_drawn(projection: &Projection, location: &NormalisedValue) -> bool {
+    !(projection.is_full_circle() && location.0 >= 1.0)
+}
+
+/// Anchors text positioned at an angle so that it extends away from the centre.
+pub(crate) fn text_anchor(angle: f32) -> &'static str {
+    match angle.sin() {
+        s if s > 0.01 => "start",
+        s if s < -0.01 => "end",
+        _ => "middle",
+    }
+}
+
+/// Describes an arc as an SVG path.
+pub(crate) fn arc(cx: f32, cy: f32, radius: f32, start_angle: f32, end_angle: f32) -> String {
+    let (x, y) = to_cartesian(cx, cy, radius, start_angle);
+    format!(
+        "M{x},{y} {}",
+        arc_to(cx, cy, radius, start_angle, end_angle)
+    )
+}
+
+/// Describes an annular sector as an SVG path. If the inner radius is zero then the sector
+/// is drawn to the centre.
+pub(crate) fn arc_path(
+    cx: f32,
+    cy: f32,
+    inner_radius: f32,
+    outer_radius: f32,
+    start_angle: f32,
+    end_angle: f32,
+) -> String {
+    let outer = arc(cx, cy, outer_radius, start_angle, end_angle);
+    if inner_radius > 0.0 {
+        let (x, y) = to_cartesian(cx, cy, inner_radius, end_angle);
+        let inner = arc_to(cx, cy, inner_radius, end_angle, start_angle);
+        format!("{outer} L{x},{y} {inner} Z")
+    } else {
+        format!("{outer} L{cx},{cy} Z")
+    }
+}
+
+/// Projects normalised angular and radial values onto a circle, or a part of one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Projection {
+    /// The centre position
+    pub x: f32,
+    /// The centre position
+    pub y: f32,
+    /// The distance from the centre representing a normalised radial value of 0
+    pub inner_radius: f32,
+    /// The distance from the centre representing a normalised radial value of 1
+    pub radius: f32,
+    /// The angle representing a normalised angular value of 0
+    pub start_angle: f32,
+    /// The angle representing a normalised angular value of 1
+    pub end_angle: f32,
+}
+
+impl Projection {
+    /// The angle of a normalised angular value
+    pub fn angle(&self, value: &NormalisedValue) -> f32 {
+        self.start_angle + value.0 * (self.end_angle - self.start_angle)
+    }
+
+    /// The distance from the centre of a normalised radial value
+    pub fn distance(&self, value: &NormalisedValue) -> f32 {
+        self.inner_radius + value.0 * (self.radius - self.inner_radius)
+    }
+
+    /// Whether the projection describes a full circle
+    pub fn is_full_circle(&self) -> bool {
+        (self.end_angle - self.start_angle).abs() >= std::f32::consts::TAU - 1e-4
+    }
+
+    /// The SVG position of normalised angular and radial values
+    pub fn project(&self, angle: &NormalisedValue, radius: &NormalisedValue) -> (f32, f32) {
+        to_cartesian(self.x, self.y, self.distance(radius), self.angle(angle))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use super::*;
+
+    #[test]
+    fn test_arc_path() {
+        assert_eq!(
+            arc_path(0.0, 0.0, 0.0, 1.0, 0.0, PI),
+            "M0,-1 A1,1 0 0 1 1,0.00000004371139 A1,1 0 0 1 -0.00000008742278,1 L0,0 Z"
+        );
+        assert_eq!(
+            arc_path(0.0, 0.0, 1.0, 2.0, 0.0, PI),
+            "M0,-2 A2,2 0 0 1 2,0.00000008742278 A2,2 0 0 1 -0.00000017484555,2 L-0.00000008742278,1 A1,1 0 0 0 1,0.00000004371139 A1,1 0 0 0 0,-1 Z"
+        );
+    }
+
+    #[test]
+    fn test_is_tick_drawn() {
+        let projection = |end_angle| Projection {
+            x: 10.0,
+            y: 10.0,
+            inner_radius: 2.0,
+            radius: 6.0,
+            start_angle: 0.0,
+            end_angle,
+        };
+
+        assert!(is_tick_drawn(&projection(2.0 * PI), &NormalisedValue(0.0)));
+        assert!(!is_tick_drawn(&projection(2.0 * PI), &NormalisedValue(1.0)));
+        assert!(is_tick_drawn(&projection(PI), &NormalisedValue(1.0)));
+    }
+
+    #[test]
+    fn test_text_anchor() {
+        assert_eq!(text_anchor(0.0), "middle");
+        assert_eq!(text_anchor(PI * 0.5), "start");
+        assert_eq!(text_anchor(PI), "middle");
+        assert_eq!(text_anchor(PI * 1.5), "end");
+    }
+
+    #[test]
+    fn test_projection() {
+        let projection = Projection {
+            x: 10.0,
+            y: 10.0,
+            inner_radius: 2.0,
+            radius: 6.0,
+            start_angle: 0.0,
+            end_angle: PI,
+        };
+
+        assert_eq!(projection.distance(&NormalisedValue(0.5)), 4.0);
+        assert_eq!(projection.angle(&NormalisedValue(0.5)), PI * 0.5);
+        assert_eq!(
+            projection.project(&NormalisedValue(0.0), &NormalisedValue(1.0)),
+            (10.0, 4.0)
+        );
+        assert!(!projection.is_full_circle());
+    }
+}
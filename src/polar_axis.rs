@@ -0,0 +1,182 @@
+/// A PolarAxis renders the grid of a chart in polar space. The ticks of a radial scale are drawn
+/// as rings around a centre, and the ticks of an angular scale are drawn as spokes out from the
+/// centre, extending beyond the outermost ring by the tick length.
+///
+/// Angles are expressed in degrees, clockwise from 12 o'clock.
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * polar-axis - the axis as a whole
+/// * ring - a ring of the radial scale
+/// * tick - a spoke of the angular scale
+/// * text - the axis text
+use std::{marker::PhantomData, rc::Rc};
+
+use yew::prelude::*;
+
+use crate::{
+    axis::{Scale, Tick},
+    polar::{arc, is_tick_drawn, text_anchor, to_cartesian, Projection},
+    series::Scalar,
+};
+
+#[derive(Properties, Clone)]
+pub struct Props<A: Scalar, B: Scalar> {
+    /// A name given to the axis that will be used for CSS classes
+    pub name: AttrValue,
+    /// The scaling conversion around the circle
+    pub angular_scale: Rc<dyn Scale<Scalar = A>>,
+    /// The scaling conversion from the centre of the circle
+    pub radial_scale: Rc<dyn Scale<Scalar = B>>,
+    /// The centre position
+    pub x: f32,
+    /// The centre position
+    pub y: f32,
+    /// The SVG radius representing the maximum of the radial scale
+    pub radius: f32,
+    /// The SVG radius representing the minimum of the radial scale
+    #[prop_or_default]
+    pub inner_radius: f32,
+    /// The angle in degrees representing the minimum of the angular scale
+    #[prop_or_default]
+    pub start_angle: f32,
+    /// The angle in degrees representing the maximum of the angular scale
+    #[prop_or(360.0)]
+    pub end_angle: f32,
+    /// The length of ticks beyond the outermost ring
+    pub tick_len: f32,
+}
+
+impl<A: Scalar, B: Scalar> PartialEq for Props<A, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.x == other.x
+            && self.y == other.y
+            && self.radius == other.radius
+            && self.inner_radius == other.inner_radius
+            && self.start_angle == other.start_angle
+            && self.end_angle == other.end_angle
+            && self.tick_len == other.tick_len
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.angular_scale as *const _ as *const u8,
+                &*other.angular_scale as *const _ as *const u8,
+            )
+            && std::ptr::eq(
+                &*self.radial_scale as *const _ as *const u8,
+                &*other.radial_scale as *const _ as *const u8,
+            )
+    }
+}
+
+/// The start and end of a spoke at an angle, followed by the position of its label.
+fn spoke(projection: &Projection, angle: f32, tick_len: f32) -> [(f32, f32); 3] {
+    let (x, y) = (projection.x, projection.y);
+    [
+        to_cartesian(x, y, projection.inner_radius, angle),
+        to_cartesian(x, y, projection.radius + tick_len, angle),
+        to_cartesian(x, y, projection.radius + tick_len * 2.0, angle),
+    ]
+}
+
+pub struct PolarAxis<A: Scalar, B: Scalar> {
+    phantom: PhantomData<(A, B)>,
+}
+
+impl<A: Scalar + 'static, B: Scalar + 'static> Component for PolarAxis<A, B> {
+    type Message = ();
+
+    type Properties = Props<A, B>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        PolarAxis {
+            phantom: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let p = ctx.props();
+
+        let projection = Projection {
+            x: p.x,
+            y: p.y,
+            inner_radius: p.inner_radius,
+            radius: p.radius,
+            start_angle: p.start_angle.to_radians(),
+            end_angle: p.end_angle.to_radians(),
+        };
+        let full_circle = projection.is_full_circle();
+
+        let rings = p.radial_scale.ticks().into_iter().map(|Tick { location, label }| {
+            let distance = projection.distance(&location);
+            let (x, y) = to_cartesian(p.x, p.y, distance, projection.start_angle);
+            html! {
+                <>
+                if full_circle {
+                    <circle cx={p.x.to_string()} cy={p.y.to_string()} r={distance.to_string()} fill="none" class="ring" />
+                } else {
+                    <path d={arc(p.x, p.y, distance, projection.start_angle, projection.end_angle)} fill="none" class="ring" />
+                }
+                if let Some(l) = label {
+                    <text x={x.to_string()} y={y.to_string()} text-anchor="end" dominant-baseline="middle" class="text">{l}</text>
+                }
+                </>
+            }
+        });
+
+        let spokes = p
+            .angular_scale
+            .ticks()
+            .into_iter()
+            .filter(|Tick { location, .. }| is_tick_drawn(&projection, location))
+            .map(|Tick { location, label }| {
+                let angle = projection.angle(&location);
+                let [(x1, y1), (x2, y2), (x3, y3)] = spoke(&projection, angle, p.tick_len);
+                let anchor = text_anchor(angle);
+                html! {
+                    <>
+                    <line x1={x1.to_string()} y1={y1.to_string()} x2={x2.to_string()} y2={y2.to_string()} class="tick" />
+                    if let Some(l) = label {
+                        <text x={x3.to_string()} y={y3.to_string()} text-anchor={anchor} dominant-baseline="middle" class="text">{l}</text>
+                    }
+                    </>
+                }
+            });
+
+        html! {
+            <g class={classes!("polar-axis", &p.name)}>
+                { for rings }
+                { for spokes }
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use super::*;
+
+    fn projection(end_angle: f32) -> Projection {
+        Projection {
+            x: 10.0,
+            y: 10.0,
+            inner_radius: 2.0,
+            radius: 6.0,
+            start_angle: 0.0,
+            end_angle,
+        }
+    }
+
+    #[test]
+    fn test_spoke() {
+        assert_eq!(
+            spoke(&projection(PI), 0.0, 1.0),
+            [(10.0, 8.0), (10.0, 3.0), (10.0, 2.0)]
+        );
+    }
+}
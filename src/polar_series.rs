@@ -0,0 +1,404 @@
+/// A PolarSeries represents the data of a chart in polar space. As with a [Series], data is
+/// expressed as a vector of data x/y value tuples. The x value is mapped by an angular scale to an
+/// angle around a centre, and the y value is mapped by a radial scale to a distance from that
+/// centre. Labellers receive the resulting SVG position of each point.
+///
+/// Angles are expressed in degrees, clockwise from 12 o'clock. Lines and areas join points with
+/// straight lines, and bars are rendered as wedges centred on each point's angle.
+///
+/// A name is associated with the series to facilitate styling.
+use std::{marker::PhantomData, rc::Rc};
+
+use yew::prelude::*;
+
+#[cfg(feature = "custom-tooltip")]
+use crate::series::TooltipCallback;
+use crate::{
+    axis::{NormalisedValue, Scale},
+    polar::{arc_path, Projection},
+    series::{BarType, Data, Scalar, Tooltipper, Type},
+};
+
+#[derive(Properties, Clone)]
+pub struct Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    /// A vector of data points that represents the series, along with optional labels at each point
+    pub data: Rc<Data<A, B>>,
+    /// The scaling factor for data around the circle
+    pub angular_scale: Rc<dyn Scale<Scalar = A>>,
+    /// The scaling factor for data from the centre of the circle
+    pub radial_scale: Rc<dyn Scale<Scalar = B>>,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    #[cfg(feature = "custom-tooltip")]
+    /// A callback to receive mouseover events along with tooltipper function text results. Requires
+    /// the custom-tooltip feature.
+    #[prop_or_else(|| Rc::new(Callback::noop()))]
+    pub onmouseover: Rc<TooltipCallback>,
+    /// The type of series to be rendered
+    pub series_type: Type,
+    /// An optional function that renders a string to be used for tooltips
+    #[prop_or_default]
+    pub tooltipper: Option<Rc<dyn Tooltipper<A, B>>>,
+    /// The centre position
+    pub x: f32,
+    /// The centre position
+    pub y: f32,
+    /// The SVG radius representing the maximum of the radial scale
+    pub radius: f32,
+    /// The SVG radius representing the minimum of the radial scale
+    #[prop_or_default]
+    pub inner_radius: f32,
+    /// The angle in degrees representing the minimum of the angular scale
+    #[prop_or_default]
+    pub start_angle: f32,
+    /// The angle in degrees representing the maximum of the angular scale
+    #[prop_or(360.0)]
+    pub end_angle: f32,
+    /// The angle in degrees covered by each bar. If None then the angle is divided
+    /// evenly between the data points.
+    #[prop_or_default]
+    pub wedge_angle: Option<f32>,
+}
+
+impl<A, B> Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    #[cfg(feature = "custom-tooltip")]
+    fn is_onmouseover_eq(&self, other: &Self) -> bool {
+        self.onmouseover == other.onmouseover
+    }
+    #[cfg(not(feature = "custom-tooltip"))]
+    fn is_onmouseover_eq(&self, _other: &Self) -> bool {
+        true
+    }
+
+    fn projection(&self) -> Projection {
+        Projection {
+            x: self.x,
+            y: self.y,
+            inner_radius: self.inner_radius,
+            radius: self.radius,
+            start_angle: self.start_angle.to_radians(),
+            end_angle: self.end_angle.to_radians(),
+        }
+    }
+}
+
+impl<A, B> PartialEq for Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.data, &other.data)
+            && self.name == other.name
+            && self.is_onmouseover_eq(other)
+            && self.series_type == other.series_type
+            && match (self.tooltipper.as_ref(), other.tooltipper.as_ref()) {
+                (Some(left), Some(right)) => std::ptr::eq(left as *const _ as *const u8, right as *const _ as *const u8),
+                _=> false
+            }
+            && self.x == other.x
+            && self.y == other.y
+            && self.radius == other.radius
+            && self.inner_radius == other.inner_radius
+            && self.start_angle == other.start_angle
+            && self.end_angle == other.end_angle
+            && self.wedge_angle == other.wedge_angle
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.angular_scale as *const _ as *const u8,
+                &*other.angular_scale as *const _ as *const u8,
+            )
+            && std::ptr::eq(
+                &*self.radial_scale as *const _ as *const u8,
+                &*other.radial_scale as *const _ as *const u8,
+            )
+    }
+}
+
+struct DerivedProps {
+    svg_elements: Vec<Html>,
+}
+
+pub struct PolarSeries<A, B> {
+    derived_props: DerivedProps,
+    phantom: PhantomData<(A, B)>,
+}
+
+struct ElementPoint<A, B> {
+    data_x: A,
+    data_y: B,
+    angle: f32,
+    distance: f32,
+    x: f32,
+    y: f32,
+}
+
+impl<A, B> PolarSeries<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    fn derive_props(props: &Props<A, B>) -> DerivedProps {
+        let classes = classes!("series", &props.name);
+        let projection = props.projection();
+
+        let mut svg_elements = Vec::<Html>::with_capacity(props.data.len() * 2);
+        let mut element_points = Vec::with_capacity(props.data.len());
+
+        let bounds = -0.001..=1.001;
+
+        for (data_x, data_y, labeller) in props.data.iter() {
+            let (data_x, data_y) = (*data_x, *data_y);
+            let angular = props.angular_scale.normalise(data_x);
+            let radial = props.radial_scale.normalise(data_y);
+            if bounds.contains(&angular.0) && bounds.contains(&radial.0) {
+                let (x, y) = projection.project(&angular, &radial);
+
+                if let Some(l) = labeller {
+                    svg_elements.push(html! {
+                        <g class={classes.clone()}>
+                            {l(x, y)}
+                        </g>
+                    });
+                }
+
+                element_points.push(ElementPoint {
+                    data_x,
+                    data_y,
+                    angle: projection.angle(&angular),
+                    distance: projection.distance(&radial),
+                    x,
+                    y,
+                });
+            }
+        }
+
+        draw_chart(
+            &element_points,
+            props,
+            &projection,
+            &mut svg_elements,
+            &classes,
+        );
+
+        DerivedProps { svg_elements }
+    }
+}
+
+/// Half of the angle covered by each bar, being either the wedge angle in degrees or the angle of
+/// the projection divided evenly between a number of points.
+fn half_wedge(projection: &Projection, wedge_angle: Option<f32>, points: usize) -> f32 {
+    wedge_angle
+        .map(|a| a.to_radians())
+        .unwrap_or_else(|| (projection.end_angle - projection.start_angle) / (points.max(1) as f32))
+        * 0.5
+}
+
+/// The inner and outer distances from the centre of a bar reaching a distance.
+fn bar_distances(projection: &Projection, bar_type: BarType, distance: f32) -> (f32, f32) {
+    match bar_type {
+        BarType::Rise => (projection.distance(&NormalisedValue(0.0)), distance),
+        BarType::Drop => (distance, projection.distance(&NormalisedValue(1.0))),
+    }
+}
+
+/// The indices of the points joined by each line segment. On a full circle the last point is
+/// joined back to the first, as with an area.
+fn segments(projection: &Projection, points: usize) -> Vec<(usize, usize)> {
+    let closing = (projection.is_full_circle() && points > 2).then(|| (points - 1, 0));
+    (1..points).map(|i| (i - 1, i)).chain(closing).collect()
+}
+
+fn draw_chart<A, B>(
+    element_points: &[ElementPoint<A, B>],
+    props: &Props<A, B>,
+    projection: &Projection,
+    svg_elements: &mut Vec<Html>,
+    classes: &Classes,
+) where
+    A: Scalar,
+    B: Scalar,
+{
+    #[cfg(feature = "custom-tooltip")]
+    fn onmouseover(cb: &Rc<TooltipCallback>, title: String) -> impl Fn(MouseEvent) {
+        let cb = Rc::clone(cb);
+        move |e| {
+            (*cb).emit((e, title.clone()));
+        }
+    }
+
+    match props.series_type {
+        Type::Area => {
+            let points = element_points
+                .iter()
+                .map(|p| format!("{},{} ", p.x, p.y))
+                .collect::<Vec<_>>()
+                .concat();
+            svg_elements.push(html! {
+                <polygon class={classes.clone()} fill="none" points={points} />
+            })
+        }
+        Type::Bar(bar_type) => {
+            let half_wedge = half_wedge(projection, props.wedge_angle, props.data.len());
+            for point in element_points.iter() {
+                let (inner, outer) = bar_distances(projection, bar_type, point.distance);
+
+                if inner != outer {
+                    let d = arc_path(
+                        projection.x,
+                        projection.y,
+                        inner,
+                        outer,
+                        point.angle - half_wedge,
+                        point.angle + half_wedge,
+                    );
+
+                    #[cfg(feature = "custom-tooltip")]
+                    let html = {
+                        let title = if let Some(tt) = &props.tooltipper {
+                            tt(point.data_x, point.data_y)
+                        } else {
+                            String::default()
+                        };
+                        html! {
+                            <path d={d} class={classes!(classes.clone(), "bar-chart")}
+                                onmouseover={onmouseover(&props.onmouseover, title)} />
+                        }
+                    };
+                    #[cfg(not(feature = "custom-tooltip"))]
+                    let html = html! {
+                        <path d={d} class={classes!(classes.clone(), "bar-chart")}>
+                        {
+                            if let Some(tt) = &props.tooltipper {
+                                html! {
+                                    <title>{tt(point.data_x, point.data_y)}</title>
+                                }
+                            } else {
+                                html!()
+                            }
+                        }
+                        </path>
+                    };
+
+                    svg_elements.push(html);
+                }
+            }
+        }
+        Type::Line => {
+            for (i1, i2) in segments(projection, element_points.len()) {
+                let (p1, p2) = (&element_points[i1], &element_points[i2]);
+
+                #[cfg(feature = "custom-tooltip")]
+                let html = {
+                    let title = if let Some(tt) = &props.tooltipper {
+                        format!("{}-{}", tt(p1.data_x, p1.data_y), tt(p2.data_x, p2.data_y))
+                    } else {
+                        String::default()
+                    };
+                    html! {
+                        <line x1={p1.x.to_string()} y1={p1.y.to_string()} x2={p2.x.to_string()} y2={p2.y.to_string()} class={classes.clone()} fill="none"
+                        onmouseover={onmouseover(&props.onmouseover, title)} />
+                    }
+                };
+                #[cfg(not(feature = "custom-tooltip"))]
+                let html = html! {
+                    <line x1={p1.x.to_string()} y1={p1.y.to_string()} x2={p2.x.to_string()} y2={p2.y.to_string()} class={classes.clone()} fill="none">
+                    {
+                        if let Some(tt) = props.tooltipper.as_ref() {
+                            html! {
+                                <title>{tt(p1.data_x, p1.data_y)}{"-"}{tt(p2.data_x, p2.data_y)}</title>
+                            }
+                        } else {
+                            html!()
+                        }
+                    }
+                    </line>
+                };
+
+                svg_elements.push(html);
+            }
+        }
+        Type::Scatter => (),
+    }
+}
+
+impl<A: 'static, B: 'static> Component for PolarSeries<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    type Message = ();
+
+    type Properties = Props<A, B>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        PolarSeries {
+            derived_props: Self::derive_props(ctx.props()),
+            phantom: PhantomData,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.derived_props = Self::derive_props(ctx.props());
+        true
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        html! {
+            <g>
+                { self.derived_props.svg_elements.clone() }
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use super::*;
+
+    const PROJECTION: Projection = Projection {
+        x: 0.0,
+        y: 0.0,
+        inner_radius: 2.0,
+        radius: 6.0,
+        start_angle: 0.0,
+        end_angle: PI,
+    };
+
+    #[test]
+    fn test_half_wedge() {
+        assert_eq!(half_wedge(&PROJECTION, None, 4), PI / 8.0);
+        assert_eq!(half_wedge(&PROJECTION, None, 0), PI * 0.5);
+        assert_eq!(half_wedge(&PROJECTION, Some(90.0), 4), PI / 4.0);
+    }
+
+    #[test]
+    fn test_bar_distances() {
+        assert_eq!(bar_distances(&PROJECTION, BarType::Rise, 4.0), (2.0, 4.0));
+        assert_eq!(bar_distances(&PROJECTION, BarType::Drop, 4.0), (4.0, 6.0));
+    }
+
+    #[test]
+    fn test_segments() {
+        let full_circle = Projection {
+            end_angle: 2.0 * PI,
+            ..PROJECTION
+        };
+
+        assert_eq!(segments(&PROJECTION, 3), vec![(0, 1), (1, 2)]);
+        assert_eq!(segments(&full_circle, 3), vec![(0, 1), (1, 2), (2, 0)]);
+        assert_eq!(segments(&full_circle, 2), vec![(0, 1)]);
+        assert_eq!(segments(&full_circle, 0), vec![]);
+    }
+}
@@ -0,0 +1,243 @@
+/// A Radar renders one or more datasets as polygons on a web of concentric rings, with a spoke
+/// for each of the radar's axes. Each value of a dataset is plotted along its axis' spoke as
+/// a distance from the centre, with the outermost ring representing the maximum value.
+///
+/// The web, spokes and datasets are all rendered as [Series] and so may be styled as such.
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * radar - the radar as a whole
+/// * web - the concentric rings
+/// * spoke - the lines from the centre to each axis label
+/// * label - the text of each axis label
+/// * the name of each dataset - the polygon of the dataset, including any fill
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::{
+    axis::Scale,
+    linear_axis_scale::LinearScale,
+    series::{self, Labeller, Series, Type},
+};
+
+/// Describes named datasets, each having a value for every axis of the radar. Datasets with
+/// fewer values than axes are padded with zeros, and any excess values are ignored.
+pub type Data = Vec<(String, Vec<f32>)>;
+
+/// Pads or truncates values so that there is one for each axis.
+fn fit(values: &[f32], axes: usize) -> Vec<f32> {
+    values
+        .iter()
+        .copied()
+        .chain(std::iter::repeat(0.0))
+        .take(axes)
+        .collect()
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    /// The labels of each axis, in clockwise order from 12 o'clock
+    pub labels: Rc<Vec<String>>,
+    /// The datasets to be plotted. Each dataset's name is used for CSS selection.
+    pub data: Rc<Data>,
+    /// The value represented by the outermost ring
+    pub max: f32,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    /// The interval between the rings of the web
+    pub step: f32,
+    /// The SVG height of the radar
+    pub height: f32,
+    /// The SVG width of the radar
+    pub width: f32,
+    /// The start position
+    pub x: f32,
+    /// The start position
+    pub y: f32,
+}
+
+impl PartialEq for Props {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.labels, &other.labels)
+            && Rc::ptr_eq(&self.data, &other.data)
+            && self.max == other.max
+            && self.name == other.name
+            && self.step == other.step
+            && self.height == other.height
+            && self.width == other.width
+            && self.x == other.x
+            && self.y == other.y
+    }
+}
+
+struct DerivedProps {
+    scale: Rc<dyn Scale<Scalar = f32>>,
+    rings: Vec<Rc<series::Data<f32, f32>>>,
+    spokes: Rc<series::Data<f32, f32>>,
+    datasets: Vec<(String, Rc<series::Data<f32, f32>>)>,
+}
+
+pub struct Radar {
+    derived_props: DerivedProps,
+}
+
+// Labels axes with text that is anchored away from the centre of the radar.
+fn axis_label(text: String, cx: f32) -> impl Labeller {
+    move |x: f32, y: f32| {
+        let anchor = if x > cx + 0.5 {
+            "start"
+        } else if x < cx - 0.5 {
+            "end"
+        } else {
+            "middle"
+        };
+        html! {
+            <text x={x.to_string()} y={y.to_string()} text-anchor={anchor} class="label">{&text}</text>
+        }
+    }
+}
+
+impl Radar {
+    fn derive_props(props: &Props) -> DerivedProps {
+        let axes = props.labels.len();
+        let cx = props.x + props.width * 0.5;
+
+        let mut rings = Vec::new();
+        if props.step > 0.0 {
+            let mut value = props.step;
+            while value < props.max {
+                rings.push(Rc::new(series::to_radial(vec![value; axes])));
+                value += props.step;
+            }
+        }
+        rings.push(Rc::new(series::to_radial(vec![props.max; axes])));
+
+        let spokes = Rc::new(
+            series::to_radial(vec![props.max; axes])
+                .into_iter()
+                .zip(props.labels.iter())
+                .flat_map(|((x, y, _), label)| {
+                    let labeller = Rc::from(axis_label(label.clone(), cx)) as Rc<dyn Labeller>;
+                    [(0.0, 0.0, None), (x, y, Some(labeller)), (0.0, 0.0, None)]
+                })
+                .collect(),
+        );
+
+        let datasets = props
+            .data
+            .iter()
+            .map(|(name, values)| (name.clone(), Rc::new(series::to_radial(fit(values, axes)))))
+            .collect();
+
+        DerivedProps {
+            scale: Rc::new(LinearScale::new(-props.max..props.max, props.step)),
+            rings,
+            spokes,
+            datasets,
+        }
+    }
+}
+
+impl Component for Radar {
+    type Message = ();
+
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Radar {
+            derived_props: Self::derive_props(ctx.props()),
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.derived_props = Self::derive_props(ctx.props());
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let p = ctx.props();
+        let d = &self.derived_props;
+
+        html! {
+            <g class={classes!("radar", &p.name)}>
+                { for d.rings.iter().map(|ring| html! {
+                    <Series<f32, f32>
+                        series_type={Type::Area}
+                        name="web"
+                        data={Rc::clone(ring)}
+                        horizontal_scale={Rc::clone(&d.scale)}
+                        vertical_scale={Rc::clone(&d.scale)}
+                        x={p.x} y={p.y} width={p.width} height={p.height} />
+                }) }
+
+                <Series<f32, f32>
+                    series_type={Type::Line}
+                    name="spoke"
+                    data={Rc::clone(&d.spokes)}
+                    horizontal_scale={Rc::clone(&d.scale)}
+                    vertical_scale={Rc::clone(&d.scale)}
+                    x={p.x} y={p.y} width={p.width} height={p.height} />
+
+                { for d.datasets.iter().map(|(name, data)| html! {
+                    <Series<f32, f32>
+                        series_type={Type::Area}
+                        name={name.clone()}
+                        data={Rc::clone(data)}
+                        horizontal_scale={Rc::clone(&d.scale)}
+                        vertical_scale={Rc::clone(&d.scale)}
+                        x={p.x} y={p.y} width={p.width} height={p.height} />
+                }) }
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit() {
+        assert_eq!(fit(&[1.0, 2.0], 4), vec![1.0, 2.0, 0.0, 0.0]);
+        assert_eq!(fit(&[1.0, 2.0, 3.0], 2), vec![1.0, 2.0]);
+        assert_eq!(fit(&[1.0], 1), vec![1.0]);
+    }
+
+    #[test]
+    fn test_datasets_align_with_spokes() {
+        let props = Props {
+            labels: Rc::new(vec!["a".into(), "b".into(), "c".into(), "d".into()]),
+            data: Rc::new(vec![
+                ("short".into(), vec![10.0, 10.0]),
+                ("long".into(), vec![10.0, 10.0, 10.0, 10.0, 10.0, 10.0]),
+            ]),
+            max: 10.0,
+            name: "radar".into(),
+            step: 5.0,
+            height: 100.0,
+            width: 100.0,
+            x: 0.0,
+            y: 0.0,
+        };
+        let d = Radar::derive_props(&props);
+
+        let spokes = d
+            .spokes
+            .iter()
+            .skip(1)
+            .step_by(3)
+            .map(|(x, y, _)| (*x, *y))
+            .collect::<Vec<_>>();
+        for (_, data) in d.datasets.iter() {
+            assert_eq!(data.len(), spokes.len());
+        }
+        let (_, short) = &d.datasets[0];
+        assert_eq!((short[1].0, short[1].1), spokes[1]);
+        assert_eq!((short[2].0, short[2].1), (0.0, 0.0));
+        let (_, long) = &d.datasets[1];
+        assert_eq!((long[3].0, long[3].1), spokes[3]);
+    }
+}
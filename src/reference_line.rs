@@ -0,0 +1,242 @@
+/// A ReferenceLine annotates a chart with a line at a value in data space, such as a threshold
+/// or an event. Horizontal lines span the full width of the plot area at a value of the vertical
+/// scale, and vertical lines span its full height at a value of the horizontal scale. The scale is
+/// expected to be the same one provided to the chart's [Series](crate::series::Series).
+///
+/// Lines with values outside of their scale are not drawn.
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * reference-line - the annotation as a whole, along with horizontal or vertical
+/// * line - the line
+/// * label - the text of the label
+use std::{marker::PhantomData, rc::Rc};
+
+use yew::prelude::*;
+
+use crate::{axis::Scale, series::Scalar};
+
+const LABEL_OFFSET: f32 = 3.0;
+
+/// Describes the direction in which a reference line is drawn
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    /// A line across the plot area at a value of the vertical scale
+    Horizontal,
+    /// A line up the plot area at a value of the horizontal scale
+    Vertical,
+}
+
+/// Describes which end of a line a label is positioned at
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LabelPosition {
+    /// The left of a horizontal line, or the bottom of a vertical one
+    Start,
+    /// The right of a horizontal line, or the top of a vertical one
+    End,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props<S: Scalar> {
+    /// The direction of the line
+    pub direction: Direction,
+    /// The SVG height of the plot area
+    pub height: f32,
+    /// Any label to be drawn alongside the line
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+    /// Which end of the line the label is drawn at
+    #[prop_or(LabelPosition::End)]
+    pub label_position: LabelPosition,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    /// The scaling factor for the value, being the vertical scale for horizontal lines and the
+    /// horizontal scale for vertical lines
+    pub scale: Rc<dyn Scale<Scalar = S>>,
+    /// The value at which the line is drawn
+    pub value: S,
+    /// The SVG width of the plot area
+    pub width: f32,
+    /// The start position
+    pub x: f32,
+    /// The start position
+    pub y: f32,
+}
+
+impl<S: Scalar> PartialEq for Props<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.direction == other.direction
+            && self.height == other.height
+            && self.label == other.label
+            && self.label_position == other.label_position
+            && self.name == other.name
+            && self.value == other.value
+            && self.width == other.width
+            && self.x == other.x
+            && self.y == other.y
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.scale as *const _ as *const u8,
+                &*other.scale as *const _ as *const u8,
+            )
+    }
+}
+
+// The positions of a line and its label
+#[derive(Debug, PartialEq)]
+struct Layout {
+    line: (f32, f32, f32, f32),
+    label: (f32, f32),
+    anchor: &'static str,
+    baseline: &'static str,
+}
+
+/// Lays out a line at a normalised location within a plot area given as x, y, width and height.
+/// Returns None if the location is outside of the plot area.
+fn layout(
+    direction: Direction,
+    label_position: LabelPosition,
+    location: f32,
+    (x, y, width, height): (f32, f32, f32, f32),
+) -> Option<Layout> {
+    if !(0.0..=1.0).contains(&location) {
+        return None;
+    }
+    Some(match (direction, label_position) {
+        (Direction::Horizontal, _) => {
+            let ly = y + height - location * height;
+            let (label_x, anchor) = match label_position {
+                LabelPosition::Start => (x + LABEL_OFFSET, "start"),
+                LabelPosition::End => (x + width - LABEL_OFFSET, "end"),
+            };
+            Layout {
+                line: (x, ly, x + width, ly),
+                label: (label_x, ly - LABEL_OFFSET),
+                anchor,
+                baseline: "auto",
+            }
+        }
+        (Direction::Vertical, LabelPosition::Start) => {
+            let lx = x + location * width;
+            Layout {
+                line: (lx, y, lx, y + height),
+                label: (lx + LABEL_OFFSET, y + height - LABEL_OFFSET),
+                anchor: "start",
+                baseline: "auto",
+            }
+        }
+        (Direction::Vertical, LabelPosition::End) => {
+            let lx = x + location * width;
+            Layout {
+                line: (lx, y, lx, y + height),
+                label: (lx + LABEL_OFFSET, y + LABEL_OFFSET),
+                anchor: "start",
+                baseline: "hanging",
+            }
+        }
+    })
+}
+
+pub struct ReferenceLine<S: Scalar> {
+    phantom: PhantomData<S>,
+}
+
+impl<S: Scalar + 'static> Component for ReferenceLine<S> {
+    type Message = ();
+
+    type Properties = Props<S>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        ReferenceLine {
+            phantom: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let p = ctx.props();
+
+        let Layout {
+            line: (x1, y1, x2, y2),
+            label: (label_x, label_y),
+            anchor,
+            baseline,
+        } = match layout(
+            p.direction,
+            p.label_position,
+            p.scale.normalise(p.value).0,
+            (p.x, p.y, p.width, p.height),
+        ) {
+            Some(layout) => layout,
+            None => return html!(),
+        };
+        let class = match p.direction {
+            Direction::Horizontal => "horizontal",
+            Direction::Vertical => "vertical",
+        };
+
+        html! {
+            <g class={classes!("reference-line", class, &p.name)}>
+                <line x1={x1.to_string()} y1={y1.to_string()} x2={x2.to_string()} y2={y2.to_string()} class="line" />
+                if let Some(l) = &p.label {
+                    <text x={label_x.to_string()} y={label_y.to_string()} text-anchor={anchor} dominant-baseline={baseline} class="label">{l}</text>
+                }
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AREA: (f32, f32, f32, f32) = (10.0, 20.0, 100.0, 50.0);
+
+    #[test]
+    fn test_layout_horizontal() {
+        assert_eq!(
+            layout(Direction::Horizontal, LabelPosition::End, 0.2, AREA),
+            Some(Layout {
+                line: (10.0, 60.0, 110.0, 60.0),
+                label: (107.0, 57.0),
+                anchor: "end",
+                baseline: "auto",
+            })
+        );
+        assert_eq!(
+            layout(Direction::Horizontal, LabelPosition::Start, 0.2, AREA).map(|l| l.label),
+            Some((13.0, 57.0))
+        );
+    }
+
+    #[test]
+    fn test_layout_vertical() {
+        assert_eq!(
+            layout(Direction::Vertical, LabelPosition::End, 0.5, AREA),
+            Some(Layout {
+                line: (60.0, 20.0, 60.0, 70.0),
+                label: (63.0, 23.0),
+                anchor: "start",
+                baseline: "hanging",
+            })
+        );
+        assert_eq!(
+            layout(Direction::Vertical, LabelPosition::Start, 0.5, AREA).map(|l| l.label),
+            Some((63.0, 67.0))
+        );
+    }
+
+    #[test]
+    fn test_layout_outside() {
+        assert_eq!(
+            layout(Direction::Vertical, LabelPosition::End, 1.5, AREA),
+            None
+        );
+        assert_eq!(
+            layout(Direction::Horizontal, LabelPosition::End, -0.1, AREA),
+            None
+        );
+    }
+}
@@ -0,0 +1,157 @@
+/// A Region annotates a chart by shading a rectangle defined in data space, such as to show where
+/// conditions are good, marginal or unsuitable. A region may be bounded by a range of x values,
+/// a range of y values, or both. When a range is not provided then the region extends across the
+/// full width or height of the plot area. The scales are expected to be the same ones provided
+/// to the chart's [Series](crate::series::Series).
+///
+/// Regions are clipped to the plot area, and regions falling entirely outside of it are not drawn.
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * region - the region as a whole
+/// * area - the shaded rectangle
+/// * label - the text of the label
+use std::{marker::PhantomData, ops::Range, rc::Rc};
+
+use yew::prelude::*;
+
+use crate::{axis::Scale, series::Scalar};
+
+const LABEL_OFFSET: f32 = 3.0;
+
+/// Clips a normalised range to the plot area, returning the ordered start and end of the range
+/// or None if it falls entirely outside.
+fn clip(start: f32, end: f32) -> Option<(f32, f32)> {
+    let (start, end) = (start.min(end).max(0.0), start.max(end).min(1.0));
+    (start < end).then_some((start, end))
+}
+
+#[derive(Properties, Clone)]
+pub struct Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    /// The SVG height of the plot area
+    pub height: f32,
+    /// The scaling factor for data along the x axis
+    pub horizontal_scale: Rc<dyn Scale<Scalar = A>>,
+    /// Any label to be drawn in the top left of the region
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    /// The scaling factor for data along the y axis
+    pub vertical_scale: Rc<dyn Scale<Scalar = B>>,
+    /// The SVG width of the plot area
+    pub width: f32,
+    /// The start position
+    pub x: f32,
+    /// The range of x values to shade. If None then the full width is shaded.
+    #[prop_or_default]
+    pub x_range: Option<Range<A>>,
+    /// The start position
+    pub y: f32,
+    /// The range of y values to shade. If None then the full height is shaded.
+    #[prop_or_default]
+    pub y_range: Option<Range<B>>,
+}
+
+impl<A, B> PartialEq for Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.height == other.height
+            && self.label == other.label
+            && self.name == other.name
+            && self.width == other.width
+            && self.x == other.x
+            && self.x_range == other.x_range
+            && self.y == other.y
+            && self.y_range == other.y_range
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.horizontal_scale as *const _ as *const u8,
+                &*other.horizontal_scale as *const _ as *const u8,
+            )
+            && std::ptr::eq(
+                &*self.vertical_scale as *const _ as *const u8,
+                &*other.vertical_scale as *const _ as *const u8,
+            )
+    }
+}
+
+pub struct Region<A, B> {
+    phantom: PhantomData<(A, B)>,
+}
+
+impl<A: 'static, B: 'static> Component for Region<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    type Message = ();
+
+    type Properties = Props<A, B>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Region {
+            phantom: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let p = ctx.props();
+
+        let x_extent = match &p.x_range {
+            Some(r) => clip(
+                p.horizontal_scale.normalise(r.start).0,
+                p.horizontal_scale.normalise(r.end).0,
+            ),
+            None => Some((0.0, 1.0)),
+        };
+        let y_extent = match &p.y_range {
+            Some(r) => clip(
+                p.vertical_scale.normalise(r.start).0,
+                p.vertical_scale.normalise(r.end).0,
+            ),
+            None => Some((0.0, 1.0)),
+        };
+        let (Some((x_start, x_end)), Some((y_start, y_end))) = (x_extent, y_extent) else {
+            return html!();
+        };
+
+        let x = p.x + x_start * p.width;
+        let y = p.y + p.height - y_end * p.height;
+        let width = (x_end - x_start) * p.width;
+        let height = (y_end - y_start) * p.height;
+
+        html! {
+            <g class={classes!("region", &p.name)}>
+                <rect x={x.to_string()} y={y.to_string()} width={width.to_string()} height={height.to_string()} class="area" />
+                if let Some(l) = &p.label {
+                    <text x={(x + LABEL_OFFSET).to_string()} y={(y + LABEL_OFFSET).to_string()} dominant-baseline="hanging" class="label">{l}</text>
+                }
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clip() {
+        assert_eq!(clip(0.2, 0.6), Some((0.2, 0.6)));
+        assert_eq!(clip(0.6, 0.2), Some((0.2, 0.6)));
+        assert_eq!(clip(-0.5, 1.5), Some((0.0, 1.0)));
+        assert_eq!(clip(1.2, 1.5), None);
+        assert_eq!(clip(0.5, 0.5), None);
+    }
+}
@@ -6,6 +6,10 @@
 /// label for that point.
 ///
 /// A name is associated with the series to facilitate styling.
+///
+/// The points of a series, being its bars, markers and labels, report clicks, double-clicks and
+/// the pointer entering and leaving them through callbacks. Lines and areas join points and so do
+/// not report such events. Selected points are given a "selected" CSS class.
 use std::{cmp, marker::PhantomData, ops, rc::Rc};
 
 use gloo_events::EventListener;
@@ -13,7 +17,10 @@ use wasm_bindgen::JsCast;
 use web_sys::{Element, SvgElement};
 use yew::{prelude::*, virtual_dom::VNode};
 
-use crate::axis::Scale;
+use crate::{
+    axis::Scale,
+    marker::{Colourer, Marker, Sizer, DEFAULT_MARKER_SIZE},
+};
 
 /// The Scalar trait expresses the behaviour of data
 /// that can be used within a series.
@@ -48,13 +55,41 @@ pub trait Tooltipper<A: Scalar, B: Scalar>: Fn(A, B) -> String {}
 
 impl<A: Scalar, B: Scalar, T: Fn(A, B) -> String> Tooltipper<A, B> for T {}
 
+/// Describes a closure that takes data values (x, y) and produces rich tooltip Html for
+/// each datapoint.
+pub trait HtmlTooltipper<A: Scalar, B: Scalar>: Fn(A, B) -> Html {}
+
+impl<A: Scalar, B: Scalar, T: Fn(A, B) -> Html> HtmlTooltipper<A, B> for T {}
+
 /// A callback for displaying tooltip data given a mouseover event.
 #[cfg(feature = "custom-tooltip")]
 pub type TooltipCallback = Callback<(MouseEvent, String)>;
 
+/// A callback for displaying rich tooltip Html given a mouseover event.
+#[cfg(feature = "custom-tooltip")]
+pub type HtmlTooltipCallback = Callback<(MouseEvent, Html)>;
+
 /// Describes a data series with each point optionally receiving a labeller
 pub type Data<A, B> = Vec<(A, B, Option<Rc<dyn Labeller>>)>;
 
+/// Describes the data of one or more series, each associated with the name of its series
+pub type NamedData<A, B> = Vec<(AttrValue, Rc<Data<A, B>>)>;
+
+/// Describes an event occurring on a data point
+#[derive(Clone)]
+pub struct PointEvent<A, B> {
+    /// The originating event
+    pub event: MouseEvent,
+    /// The name of the series
+    pub name: AttrValue,
+    /// The index of the point within the series data
+    pub index: usize,
+    /// The x value of the point
+    pub x: A,
+    /// The y value of the point
+    pub y: B,
+}
+
 /// Convert a series of scalars, r, into a series of 2d cartesian points (x, y).
 /// The points are arranged at equally spaced angles, each with distance r from the origin
 pub fn to_radial(data: Vec<f32>) -> Data<f32, f32> {
@@ -115,7 +150,7 @@ pub enum Type {
     Bar(BarType),
     /// Plots the data points as lines
     Line,
-    /// Does not join the data points - relies on a labeller
+    /// Does not join the data points - relies on a marker or labeller
     Scatter,
 }
 
@@ -138,6 +173,9 @@ where
     pub data: Rc<Data<A, B>>,
     /// The SVG height of the series
     pub height: f32,
+    /// Whether the series is hidden. Hidden series keep their layout.
+    #[prop_or_default]
+    pub hidden: bool,
     /// The scaling factor for data along the x axis
     pub horizontal_scale: Rc<dyn Scale<Scalar = A>>,
     /// The horizontal scale step is used to determine when there is a gap in data, such that
@@ -146,13 +184,58 @@ where
     /// If None then this functionality is disabled.
     #[prop_or_default]
     pub horizontal_scale_step: Option<A>,
+    #[cfg(feature = "custom-tooltip")]
+    /// An optional function that renders Html to be used for tooltips, being provided to the
+    /// ontooltip callback. Requires the custom-tooltip feature.
+    #[prop_or_default]
+    pub html_tooltipper: Option<Rc<dyn HtmlTooltipper<A, B>>>,
+    /// An optional marker to be drawn at each data point
+    #[prop_or_default]
+    pub marker: Option<Marker>,
+    /// An optional function that colours each marker given its data values. If None then markers
+    /// are expected to be coloured via CSS.
+    #[prop_or_default]
+    pub marker_colourer: Option<Rc<dyn Colourer<A, B>>>,
+    /// The SVG size of each marker
+    #[prop_or(DEFAULT_MARKER_SIZE)]
+    pub marker_size: f32,
+    /// An optional function that sizes each marker given its data values, overriding the
+    /// marker size
+    #[prop_or_default]
+    pub marker_sizer: Option<Rc<dyn Sizer<A, B>>>,
     /// A name to be used for CSS selection
     pub name: AttrValue,
+    /// A callback to receive clicks on data points. Line and area series only report events on
+    /// points that have a marker or label.
+    #[prop_or_default]
+    pub onclick: Callback<PointEvent<A, B>>,
+    /// A callback to receive double-clicks on data points. Line and area series only report
+    /// events on points that have a marker or label.
+    #[prop_or_default]
+    pub ondblclick: Callback<PointEvent<A, B>>,
     #[cfg(feature = "custom-tooltip")]
-    /// A callback to receive mouseover events along with tooltipper function text results. Requires
-    /// the custom-tooltip feature.
+    /// A callback to receive mouseover events along with tooltipper function text results. Not
+    /// called when an html_tooltipper provides the tooltip instead. Requires the custom-tooltip
+    /// feature.
     #[prop_or_else(|| Rc::new(Callback::noop()))]
     pub onmouseover: Rc<TooltipCallback>,
+    /// A callback to receive the pointer entering a data point. Line and area series only report
+    /// events on points that have a marker or label.
+    #[prop_or_default]
+    pub onpointenter: Callback<PointEvent<A, B>>,
+    /// A callback to receive the pointer leaving a data point. Line and area series only report
+    /// events on points that have a marker or label.
+    #[prop_or_default]
+    pub onpointleave: Callback<PointEvent<A, B>>,
+    #[cfg(feature = "custom-tooltip")]
+    /// A callback to receive mouseover events along with html_tooltipper function results.
+    /// Requires the custom-tooltip feature.
+    #[prop_or_default]
+    pub ontooltip: HtmlTooltipCallback,
+    /// The indices of data points that are selected. Line and area series only convey selection
+    /// through the markers and labels of their points.
+    #[prop_or_default]
+    pub selected: Vec<usize>,
     /// The type of series to be rendered
     pub series_type: Type,
     /// An optional function that renders a string to be used for tooltips
@@ -176,6 +259,18 @@ where
     #[cfg(feature = "custom-tooltip")]
     fn is_onmouseover_eq(&self, other: &Self) -> bool {
         self.onmouseover == other.onmouseover
+            && self.ontooltip == other.ontooltip
+            && match (
+                self.html_tooltipper.as_ref(),
+                other.html_tooltipper.as_ref(),
+            ) {
+                (Some(left), Some(right)) => std::ptr::eq(
+                    left as *const _ as *const u8,
+                    right as *const _ as *const u8,
+                ),
+                (None, None) => true,
+                _ => false,
+            }
     }
     #[cfg(not(feature = "custom-tooltip"))]
     fn is_onmouseover_eq(&self, _other: &Self) -> bool {
@@ -191,9 +286,27 @@ where
     fn eq(&self, other: &Self) -> bool {
         Rc::ptr_eq(&self.data, &other.data)
             && self.height == other.height
+            && self.hidden == other.hidden
             && self.horizontal_scale_step == other.horizontal_scale_step
+            && self.marker == other.marker
+            && match (self.marker_colourer.as_ref(), other.marker_colourer.as_ref()) {
+                (Some(left), Some(right)) => std::ptr::eq(left as *const _ as *const u8, right as *const _ as *const u8),
+                (None, None) => true,
+                _ => false
+            }
+            && self.marker_size == other.marker_size
+            && match (self.marker_sizer.as_ref(), other.marker_sizer.as_ref()) {
+                (Some(left), Some(right)) => std::ptr::eq(left as *const _ as *const u8, right as *const _ as *const u8),
+                (None, None) => true,
+                _ => false
+            }
             && self.name == other.name
+            && self.onclick == other.onclick
+            && self.ondblclick == other.ondblclick
             && self.is_onmouseover_eq(other)
+            && self.onpointenter == other.onpointenter
+            && self.onpointleave == other.onpointleave
+            && self.selected == other.selected
             && self.series_type == other.series_type
             && match (self.tooltipper.as_ref(), other.tooltipper.as_ref()) {
                 (Some(left), Some(right)) => std::ptr::eq(left as *const _ as *const u8, right as *const _ as *const u8),
@@ -219,6 +332,51 @@ struct DerivedProps {
     svg_elements: Vec<Html>,
 }
 
+// The listeners of a data point, each emitting a point event
+struct PointListeners {
+    onclick: Callback<MouseEvent>,
+    ondblclick: Callback<MouseEvent>,
+    onmouseenter: Callback<MouseEvent>,
+    onmouseleave: Callback<MouseEvent>,
+}
+
+impl PointListeners {
+    fn new<A, B>(props: &Props<A, B>, index: usize, x: A, y: B) -> Self
+    where
+        A: Scalar + 'static,
+        B: Scalar + 'static,
+    {
+        let reform = |cb: &Callback<PointEvent<A, B>>| {
+            let name = props.name.clone();
+            cb.reform(move |event| PointEvent {
+                event,
+                name: name.clone(),
+                index,
+                x,
+                y,
+            })
+        };
+        PointListeners {
+            onclick: reform(&props.onclick),
+            ondblclick: reform(&props.ondblclick),
+            onmouseenter: reform(&props.onpointenter),
+            onmouseleave: reform(&props.onpointleave),
+        }
+    }
+}
+
+// The classes of a data point, including whether it is selected
+fn point_classes<A, B>(props: &Props<A, B>, classes: &Classes, index: usize) -> Classes
+where
+    A: Scalar,
+    B: Scalar,
+{
+    classes!(
+        classes.clone(),
+        props.selected.contains(&index).then_some("selected")
+    )
+}
+
 pub struct Series<A, B> {
     derived_props: DerivedProps,
     phantom: PhantomData<(A, B)>,
@@ -228,8 +386,8 @@ pub struct Series<A, B> {
 
 impl<A, B> Series<A, B>
 where
-    A: Scalar,
-    B: Scalar,
+    A: Scalar + 'static,
+    B: Scalar + 'static,
 {
     fn derive_props(props: &Props<A, B>) -> DerivedProps {
         let classes = classes!("series", &props.name);
@@ -239,8 +397,9 @@ where
 
         let mut svg_elements = Vec::<Html>::with_capacity(props.data.len() * 2);
 
-        if props.data.len() > 0 {
-            let mut element_points = Vec::<(A, B, f32, f32)>::with_capacity(props.data.len());
+        if !props.data.is_empty() {
+            let mut element_points =
+                Vec::<(usize, A, B, f32, f32)>::with_capacity(props.data.len());
 
             let mut top_y = props.height;
 
@@ -249,7 +408,7 @@ where
 
             let data_step = props.horizontal_scale_step.unwrap_or(A::MAX);
             let mut last_data_step = -data_step;
-            for (data_x, data_y, labeller) in props.data.iter() {
+            for (index, (data_x, data_y, labeller)) in props.data.iter().enumerate() {
                 let (data_x, data_y) = (*data_x, *data_y);
                 let step = (data_x / data_step) * data_step;
                 if step - last_data_step > data_step {
@@ -263,16 +422,36 @@ where
                     let x = x + props.x;
                     let y = props.height - y + props.y;
 
-                    if let Some(l) = labeller {
+                    if let Some(marker) = props.marker {
+                        let size = props
+                            .marker_sizer
+                            .as_ref()
+                            .map(|s| s(data_x, data_y))
+                            .unwrap_or(props.marker_size);
+                        let colour = props.marker_colourer.as_ref().map(|c| c(data_x, data_y));
+                        let l = PointListeners::new(props, index, data_x, data_y);
+                        svg_elements.push(html! {
+                            <g class={point_classes(props, &classes, index)}
+                                onclick={l.onclick} ondblclick={l.ondblclick}
+                                onmouseenter={l.onmouseenter} onmouseleave={l.onmouseleave}>
+                                {marker.render(x, y, size, colour)}
+                            </g>
+                        });
+                    }
+
+                    if let Some(labeller) = labeller {
+                        let l = PointListeners::new(props, index, data_x, data_y);
                         svg_elements.push(html! {
-                            <g class={classes.clone()}>
-                                {l(x, y)}
+                            <g class={point_classes(props, &classes, index)}
+                                onclick={l.onclick} ondblclick={l.ondblclick}
+                                onmouseenter={l.onmouseenter} onmouseleave={l.onmouseleave}>
+                                {labeller(x, y)}
                             </g>
                         });
                     }
 
                     top_y = top_y.min(y);
-                    element_points.push((data_x, data_y, x, y));
+                    element_points.push((index, data_x, data_y, x, y));
                 }
 
                 last_data_step = step;
@@ -284,28 +463,48 @@ where
     }
 }
 
+/// Emits a tooltip to just one of the callbacks, preferring rich Html when it is available so
+/// that a handle wired to both does not have its content replaced.
+#[cfg(feature = "custom-tooltip")]
+fn emit_tooltip<E: Clone>(
+    cb: &Callback<(E, String)>,
+    html_cb: &Callback<(E, Html)>,
+    e: E,
+    title: &str,
+    html: &Option<Html>,
+) {
+    match html {
+        Some(h) => html_cb.emit((e, h.clone())),
+        None => cb.emit((e, title.to_string())),
+    }
+}
+
 fn draw_chart<A, B>(
-    element_points: &[(A, B, f32, f32)],
+    element_points: &[(usize, A, B, f32, f32)],
     props: &Props<A, B>,
     svg_elements: &mut Vec<VNode>,
     classes: &Classes,
 ) where
-    A: Scalar,
-    B: Scalar,
+    A: Scalar + 'static,
+    B: Scalar + 'static,
 {
     #[cfg(feature = "custom-tooltip")]
-    fn onmouseover(cb: &Rc<TooltipCallback>, title: String) -> impl Fn(MouseEvent) {
+    fn onmouseover(
+        cb: &Rc<TooltipCallback>,
+        html_cb: &HtmlTooltipCallback,
+        title: String,
+        html: Option<Html>,
+    ) -> impl Fn(MouseEvent) {
         let cb = Rc::clone(cb);
-        move |e| {
-            (*cb).emit((e, title.clone()));
-        }
+        let html_cb = html_cb.clone();
+        move |e: MouseEvent| emit_tooltip(&cb, &html_cb, e, &title, &html)
     }
 
     match props.series_type {
         Type::Area => {
             let points = element_points
                 .iter()
-                .map(|(_, _, x, y)| format!("{x},{y} "))
+                .map(|(_, _, _, x, y)| format!("{x},{y} "))
                 .collect::<Vec<_>>()
                 .concat();
             svg_elements.push(html! {
@@ -314,7 +513,7 @@ fn draw_chart<A, B>(
         }
         Type::Bar(bar_type) => {
             for point in element_points.iter() {
-                let (data_x, data_y1, x, y1) = *point;
+                let (index, data_x, data_y1, x, y1) = *point;
 
                 let (y1, y2) = match bar_type {
                     BarType::Rise => (y1, props.height + props.y),
@@ -322,6 +521,8 @@ fn draw_chart<A, B>(
                 };
 
                 if y1 != y2 {
+                    let l = PointListeners::new(props, index, data_x, data_y1);
+                    let bar_classes = classes!(point_classes(props, classes, index), "bar-chart");
                     #[cfg(feature = "custom-tooltip")]
                     let html = {
                         let title = if let Some(tt) = &props.tooltipper {
@@ -331,14 +532,18 @@ fn draw_chart<A, B>(
                         };
                         html! {
                             <line x1={x.to_string()} y1={y1.to_string()} x2={x.to_string()} y2={y2.to_string()}
-                                class={classes!(classes.clone(), "bar-chart")}
-                                onmouseover={onmouseover(&props.onmouseover, title)}/>
+                                class={bar_classes}
+                                onclick={l.onclick} ondblclick={l.ondblclick}
+                                onmouseenter={l.onmouseenter} onmouseleave={l.onmouseleave}
+                                onmouseover={onmouseover(&props.onmouseover, &props.ontooltip, title, props.html_tooltipper.as_ref().map(|tt| tt(data_x, data_y1)))}/>
                         }
                     };
                     #[cfg(not(feature = "custom-tooltip"))]
                     let html = html! {
                         <line x1={x.to_string()} y1={y1.to_string()} x2={x.to_string()} y2={y2.to_string()}
-                            class={classes!(classes.clone(), "bar-chart")}>
+                            class={bar_classes}
+                            onclick={l.onclick} ondblclick={l.ondblclick}
+                            onmouseenter={l.onmouseenter} onmouseleave={l.onmouseleave}>
                         {
                             if let Some(tt) = &props.tooltipper {
                                 html! {
@@ -356,11 +561,11 @@ fn draw_chart<A, B>(
             }
         }
         Type::Line => {
-            let mut last_point: Option<(A, B, f32, f32)> = None;
+            let mut last_point: Option<(usize, A, B, f32, f32)> = None;
             for point in element_points.iter() {
-                let (data_x2, data_y2, x2, y2) = *point;
+                let (_, data_x2, data_y2, x2, y2) = *point;
 
-                if let Some((data_x1, data_y1, x1, y1)) = last_point {
+                if let Some((_, data_x1, data_y1, x1, y1)) = last_point {
                     #[cfg(feature = "custom-tooltip")]
                     let html = {
                         let title = if let Some(tt) = &props.tooltipper {
@@ -370,7 +575,9 @@ fn draw_chart<A, B>(
                         };
                         html! {
                             <line x1={x1.to_string()} y1={y1.to_string()} x2={x2.to_string()} y2={y2.to_string()} class={classes.clone()} fill="none"
-                            onmouseover={onmouseover(&props.onmouseover, title)} />
+                            onmouseover={onmouseover(&props.onmouseover, &props.ontooltip, title, props.html_tooltipper.as_ref().map(|tt| html! {
+                                <>{tt(data_x1, data_y1)}{"-"}{tt(data_x2, data_y2)}</>
+                            }))} />
                         }
                     };
                     #[cfg(not(feature = "custom-tooltip"))]
@@ -434,7 +641,7 @@ where
         let p = ctx.props();
 
         html! {
-            <svg ref={self.svg.clone()}>
+            <svg ref={self.svg.clone()} visibility={p.hidden.then_some("hidden")}>
                 <line x1={p.x.to_string()} x2={(p.x + p.width).to_string()} y1=0 y2=0 />
                 { self.derived_props.svg_elements.clone() }
             </svg>
@@ -457,3 +664,56 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linear_axis_scale::LinearScale;
+
+    #[test]
+    fn test_point_classes() {
+        let scale: Rc<dyn Scale<Scalar = f32>> = Rc::new(LinearScale::new(0.0..10.0, 1.0));
+        let props = yew::props!(Props<f32, f32> {
+            data: Rc::new(vec![(1.0, 1.0, None), (2.0, 2.0, None)]),
+            height: 100.0,
+            horizontal_scale: Rc::clone(&scale),
+            name: "series",
+            selected: vec![1],
+            series_type: Type::Line,
+            vertical_scale: scale,
+            width: 100.0,
+            x: 0.0,
+            y: 0.0,
+        });
+        let classes = classes!("series", "a");
+
+        assert_eq!(point_classes(&props, &classes, 0), classes!("series", "a"));
+        assert_eq!(
+            point_classes(&props, &classes, 1),
+            classes!("series", "a", "selected")
+        );
+    }
+
+    #[cfg(feature = "custom-tooltip")]
+    #[test]
+    fn test_emit_tooltip() {
+        use std::cell::RefCell;
+
+        let emitted = Rc::new(RefCell::new(Vec::new()));
+        let cb = {
+            let emitted = Rc::clone(&emitted);
+            Callback::from(move |(_, s): ((), String)| emitted.borrow_mut().push(s))
+        };
+        let html_cb = {
+            let emitted = Rc::clone(&emitted);
+            Callback::from(move |_: ((), Html)| emitted.borrow_mut().push("html".to_string()))
+        };
+
+        emit_tooltip(&cb, &html_cb, (), "text", &None);
+        assert_eq!(*emitted.borrow(), vec!["text".to_string()]);
+
+        emitted.borrow_mut().clear();
+        emit_tooltip(&cb, &html_cb, (), "text", &Some(html! { <b>{"rich"}</b> }));
+        assert_eq!(*emitted.borrow(), vec!["html".to_string()]);
+    }
+}
@@ -0,0 +1,185 @@
+/// A Sparkline is a compact line chart for showing a trend within a small space, such as a table
+/// cell. Values are spaced evenly along the x axis and the y axis is fitted to the values, so no
+/// scales or axes are required. The sparkline renders as its own SVG element.
+///
+/// The minimum, maximum and last values may be highlighted, and a reference band may be drawn
+/// behind the line, for example to show a normal range.
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * sparkline - the sparkline as a whole
+/// * line - the line
+/// * band - the reference band
+/// * min, max and last - the highlighted points
+use std::{ops::Range, rc::Rc};
+
+use yew::prelude::*;
+
+/// Determines the range of values to fit, including any reference band.
+fn extent(values: &[f32], band: Option<&Range<f32>>) -> Option<Range<f32>> {
+    values
+        .iter()
+        .chain(band.iter().flat_map(|b| [&b.start, &b.end]))
+        .filter(|v| v.is_finite())
+        .fold(None, |extent: Option<Range<f32>>, v| match extent {
+            Some(e) => Some(e.start.min(*v)..e.end.max(*v)),
+            None => Some(*v..*v),
+        })
+}
+
+/// The index of the first value to satisfy a comparison with all others.
+fn position_by(values: &[f32], replace: impl Fn(f32, f32) -> bool) -> Option<usize> {
+    values
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| v.is_finite())
+        .fold(None, |found: Option<(usize, f32)>, (i, v)| match found {
+            Some((_, f)) if !replace(*v, f) => found,
+            _ => Some((i, *v)),
+        })
+        .map(|(i, _)| i)
+}
+
+/// The SVG top and height of a band given a mapping of values to SVG y positions. The band's
+/// range may be given in either order.
+fn band_rect(band: &Range<f32>, to_y: impl Fn(f32) -> f32) -> (f32, f32) {
+    let top = to_y(band.start.max(band.end));
+    (top, to_y(band.start.min(band.end)) - top)
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct Props {
+    /// An optional range of values to shade behind the line
+    #[prop_or_default]
+    pub band: Option<Range<f32>>,
+    /// The values to plot, spaced evenly
+    pub data: Rc<Vec<f32>>,
+    /// The SVG height of the sparkline
+    pub height: f32,
+    /// Whether to highlight the last value
+    #[prop_or_default]
+    pub highlight_last: bool,
+    /// Whether to highlight the maximum value
+    #[prop_or_default]
+    pub highlight_max: bool,
+    /// Whether to highlight the minimum value
+    #[prop_or_default]
+    pub highlight_min: bool,
+    /// The SVG radius of highlighted points. The line is inset by this amount
+    /// so that highlighted points are not clipped.
+    #[prop_or(1.5)]
+    pub marker_radius: f32,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    /// The SVG width of the sparkline
+    pub width: f32,
+}
+
+pub struct Sparkline;
+
+impl Component for Sparkline {
+    type Message = ();
+
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Sparkline
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let p = ctx.props();
+
+        let inset = p.marker_radius;
+        let (width, height) = (p.width - inset * 2.0, p.height - inset * 2.0);
+        let extent = extent(&p.data, p.band.as_ref()).unwrap_or(0.0..0.0);
+        let delta = extent.end - extent.start;
+
+        let to_x = |i: usize| {
+            let steps = p.data.len().saturating_sub(1);
+            if steps > 0 {
+                inset + i as f32 / steps as f32 * width
+            } else {
+                inset + width * 0.5
+            }
+        };
+        let to_y = |v: f32| {
+            if delta > 0.0 {
+                inset + height - (v - extent.start) / delta * height
+            } else {
+                inset + height * 0.5
+            }
+        };
+
+        let points = p
+            .data
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.is_finite())
+            .map(|(i, v)| format!("{},{} ", to_x(i), to_y(*v)))
+            .collect::<Vec<_>>()
+            .concat();
+
+        let highlight = |class: &'static str, index: Option<usize>| {
+            index.map(|i| {
+                html! {
+                    <circle cx={to_x(i).to_string()} cy={to_y(p.data[i]).to_string()} r={p.marker_radius.to_string()} class={class} />
+                }
+            })
+        };
+        let min = p
+            .highlight_min
+            .then(|| highlight("min", position_by(&p.data, |v, f| v < f)))
+            .flatten();
+        let max = p
+            .highlight_max
+            .then(|| highlight("max", position_by(&p.data, |v, f| v > f)))
+            .flatten();
+        let last = p
+            .highlight_last
+            .then(|| highlight("last", p.data.iter().rposition(|v| v.is_finite())))
+            .flatten();
+
+        html! {
+            <svg class={classes!("sparkline", &p.name)} viewBox={format!("0 0 {} {}", p.width, p.height)} preserveAspectRatio="none">
+                if let Some((y, height)) = p.band.as_ref().map(|band| band_rect(band, to_y)) {
+                    <rect x="0" y={y.to_string()} width={p.width.to_string()} height={height.to_string()} class="band" />
+                }
+                <polyline points={points} fill="none" vector-effect="non-scaling-stroke" class="line" />
+                {for min}
+                {for max}
+                {for last}
+            </svg>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extent() {
+        assert_eq!(extent(&[3.0, 1.0, f32::NAN, 2.0], None), Some(1.0..3.0));
+        assert_eq!(extent(&[3.0, 1.0], Some(&(0.0..2.0))), Some(0.0..3.0));
+        assert_eq!(extent(&[], None), None);
+    }
+
+    #[test]
+    fn test_position_by() {
+        let values = [3.0, 1.0, 4.0, 1.0, f32::NAN];
+
+        assert_eq!(position_by(&values, |v, f| v < f), Some(1));
+        assert_eq!(position_by(&values, |v, f| v > f), Some(2));
+        assert_eq!(position_by(&[], |v, f| v > f), None);
+    }
+
+    #[test]
+    fn test_band_rect() {
+        let to_y = |v: f32| 10.0 - v;
+
+        assert_eq!(band_rect(&(2.0..5.0), to_y), (5.0, 3.0));
+        assert_eq!(band_rect(&(5.0..2.0), to_y), (5.0, 3.0));
+    }
+}
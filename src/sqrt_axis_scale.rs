@@ -0,0 +1,115 @@
+/// A SqrtScale represents a square root scale for floating point values within a fixed range.
+/// A step is also expressed and indicates the interval to be used for each tick on the axis.
+///
+/// Square root scales are typically used to size markers such that a marker's area, rather than
+/// its radius, is proportional to its value.
+use std::{ops::Range, rc::Rc};
+
+use crate::{
+    axis::{InvertibleScale, NormalisedValue, Scale, Tick},
+    linear_axis_scale::{Labeller, LinearScale},
+};
+
+#[derive(Clone)]
+pub struct SqrtScale {
+    linear: LinearScale,
+}
+
+impl SqrtScale {
+    /// Create a new scale with a range and step and labels as a integers
+    pub fn new(range: Range<f32>, step: f32) -> SqrtScale {
+        SqrtScale {
+            linear: LinearScale::new(range, step),
+        }
+    }
+
+    /// Create a new scale with a range and step and a custom labeller
+    pub fn with_labeller(
+        range: Range<f32>,
+        step: f32,
+        labeller: Option<Rc<dyn Labeller>>,
+    ) -> SqrtScale {
+        SqrtScale {
+            linear: LinearScale::with_labeller(range, step, labeller),
+        }
+    }
+}
+
+// Values outside of the range are reflected so that they remain outside of it.
+fn sqrt(value: f32) -> f32 {
+    value.abs().sqrt().copysign(value)
+}
+
+impl Scale for SqrtScale {
+    type Scalar = f32;
+
+    fn ticks(&self) -> Vec<Tick> {
+        self.linear
+            .ticks()
+            .into_iter()
+            .map(|Tick { location, label }| Tick {
+                location: NormalisedValue(sqrt(location.0)),
+                label,
+            })
+            .collect()
+    }
+
+    fn normalise(&self, value: Self::Scalar) -> NormalisedValue {
+        NormalisedValue(sqrt(self.linear.normalise(value).0))
+    }
+}
+
+impl InvertibleScale for SqrtScale {
+    fn denormalise(&self, value: NormalisedValue) -> Self::Scalar {
+        self.linear
+            .denormalise(NormalisedValue(value.0 * value.0.abs()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale() {
+        let scale = SqrtScale::new(0.0..100.0, 25.0);
+
+        assert_eq!(
+            scale.ticks(),
+            vec![
+                Tick {
+                    location: NormalisedValue(0.0),
+                    label: Some("0".to_string())
+                },
+                Tick {
+                    location: NormalisedValue(0.5),
+                    label: Some("25".to_string())
+                },
+                Tick {
+                    location: NormalisedValue(0.70710677),
+                    label: Some("50".to_string())
+                },
+                Tick {
+                    location: NormalisedValue(0.8660254),
+                    label: Some("75".to_string())
+                },
+                Tick {
+                    location: NormalisedValue(1.0),
+                    label: Some("100".to_string())
+                }
+            ]
+        );
+
+        assert_eq!(scale.normalise(25.0), NormalisedValue(0.5));
+        assert_eq!(scale.normalise(-25.0), NormalisedValue(-0.5));
+        assert_eq!(scale.denormalise(NormalisedValue(0.5)), 25.0);
+        assert_eq!(scale.denormalise(NormalisedValue(-0.5)), -25.0);
+    }
+
+    #[test]
+    fn test_zero_range() {
+        let scale = SqrtScale::new(1.0..1.0, 0.25);
+
+        assert_eq!(scale.normalise(1.0), NormalisedValue(0.0));
+    }
+}
@@ -4,7 +4,7 @@ use chrono::TimeZone;
 use chrono::{DateTime, Duration, Local, Utc};
 use std::{ops::Range, rc::Rc};
 
-use crate::axis::{NormalisedValue, Scale, Tick};
+use crate::axis::{InvertibleScale, NormalisedValue, Scale, Tick};
 
 /// An axis labeller is a closure that produces a string given a value within the axis scale
 pub trait Labeller: Fn(i64) -> String {}
@@ -88,6 +88,15 @@ impl Scale for TimeScale {
     }
 }
 
+impl InvertibleScale for TimeScale {
+    fn denormalise(&self, value: NormalisedValue) -> Self::Scalar {
+        // Timestamps exceed the precision of f32, so the span is not derived from the scale
+        let delta = self.time.end - self.time.start;
+        let span = if delta != 0 { delta as f64 } else { 1.0 };
+        self.time.start + (value.0 as f64 * span).round() as i64
+    }
+}
+
 struct TimeScaleInclusiveIter {
     pub time_from: i64,
     pub time_to: i64,
@@ -161,6 +170,10 @@ mod tests {
             scale.normalise(end_date.sub(Duration::days(2)).timestamp_millis()),
             NormalisedValue(0.5)
         );
+        assert_eq!(
+            scale.denormalise(NormalisedValue(0.5)),
+            end_date.sub(Duration::days(2)).timestamp_millis()
+        );
     }
 
     #[test]
@@ -0,0 +1,272 @@
+/// A Timeline renders tasks as horizontal bars spanning their start and end timestamps, with
+/// each task being placed in a row. Tasks that overlap within a row are placed in separate lanes
+/// of the row. Rows are labelled to the left of the timeline.
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * timeline - the timeline as a whole
+/// * row - the background of a row
+/// * row-label - the text labelling a row
+/// * task - a task's bar, along with the task's category
+/// * label - the text of a task's label
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::axis::Scale;
+#[cfg(feature = "custom-tooltip")]
+use crate::series::TooltipCallback;
+
+/// Describes a task to be rendered as a bar
+#[derive(Clone, Debug, PartialEq)]
+pub struct Task {
+    /// The index of the row that the task is rendered in
+    pub row: usize,
+    /// The start of the task as a timestamp in milliseconds
+    pub start: i64,
+    /// The end of the task as a timestamp in milliseconds
+    pub end: i64,
+    /// Any label to be drawn within the task's bar
+    pub label: Option<String>,
+    /// Any category to be used for CSS selection
+    pub category: Option<String>,
+}
+
+/// Describes the tasks of a timeline
+pub type Data = Vec<Task>;
+
+/// Describes a closure that takes a task and produces a tooltip string for it.
+pub trait Tooltipper: Fn(&Task) -> String {}
+
+impl<T: Fn(&Task) -> String> Tooltipper for T {}
+
+/// Assigns each task a lane within its row such that no tasks within a lane overlap. Returns
+/// the lane of each task and the number of lanes in each row.
+fn assign_lanes(data: &Data, rows: usize) -> (Vec<usize>, Vec<usize>) {
+    let mut order = (0..data.len()).collect::<Vec<_>>();
+    order.sort_by_key(|i| (data[*i].row, data[*i].start));
+
+    let mut lanes = vec![0; data.len()];
+    let mut lane_ends: Vec<Vec<i64>> = vec![vec![]; rows];
+    for i in order {
+        let task = &data[i];
+        if let Some(ends) = lane_ends.get_mut(task.row) {
+            let lane = match ends.iter().position(|end| *end <= task.start) {
+                Some(lane) => {
+                    ends[lane] = task.end;
+                    lane
+                }
+                None => {
+                    ends.push(task.end);
+                    ends.len() - 1
+                }
+            };
+            lanes[i] = lane;
+        }
+    }
+
+    let lane_counts = lane_ends.iter().map(|ends| ends.len().max(1)).collect();
+    (lanes, lane_counts)
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    /// The tasks to be rendered
+    pub data: Rc<Data>,
+    /// The SVG height of the timeline
+    pub height: f32,
+    /// The scaling factor for timestamps along the x axis
+    pub horizontal_scale: Rc<dyn Scale<Scalar = i64>>,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    /// A callback to receive the index of a task when it is clicked
+    #[prop_or_default]
+    pub onclick: Callback<usize>,
+    #[cfg(feature = "custom-tooltip")]
+    /// A callback to receive mouseover events along with tooltipper function text results. Requires
+    /// the custom-tooltip feature.
+    #[prop_or_else(|| Rc::new(Callback::noop()))]
+    pub onmouseover: Rc<TooltipCallback>,
+    /// The fraction of each lane's height to leave empty between bars
+    #[prop_or(0.2)]
+    pub padding: f32,
+    /// The labels of each row, in order from the top
+    pub rows: Rc<Vec<String>>,
+    /// The distance to the left of the timeline at which row labels are drawn
+    #[prop_or_default]
+    pub row_label_offset: f32,
+    /// An optional function that renders a string to be used for tooltips
+    #[prop_or_default]
+    pub tooltipper: Option<Rc<dyn Tooltipper>>,
+    /// The SVG width of the timeline
+    pub width: f32,
+    /// The start position
+    pub x: f32,
+    /// The start position
+    pub y: f32,
+}
+
+impl Props {
+    #[cfg(feature = "custom-tooltip")]
+    fn is_onmouseover_eq(&self, other: &Self) -> bool {
+        self.onmouseover == other.onmouseover
+    }
+    #[cfg(not(feature = "custom-tooltip"))]
+    fn is_onmouseover_eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl PartialEq for Props {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.data, &other.data)
+            && self.height == other.height
+            && self.name == other.name
+            && self.onclick == other.onclick
+            && self.is_onmouseover_eq(other)
+            && self.padding == other.padding
+            && Rc::ptr_eq(&self.rows, &other.rows)
+            && self.row_label_offset == other.row_label_offset
+            && match (self.tooltipper.as_ref(), other.tooltipper.as_ref()) {
+                (Some(left), Some(right)) => std::ptr::eq(left as *const _ as *const u8, right as *const _ as *const u8),
+                _=> false
+            }
+            && self.width == other.width
+            && self.x == other.x
+            && self.y == other.y
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.horizontal_scale as *const _ as *const u8,
+                &*other.horizontal_scale as *const _ as *const u8,
+            )
+    }
+}
+
+pub struct Timeline;
+
+impl Component for Timeline {
+    type Message = ();
+
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Timeline
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        #[cfg(feature = "custom-tooltip")]
+        fn onmouseover(cb: &Rc<TooltipCallback>, title: String) -> impl Fn(MouseEvent) {
+            let cb = Rc::clone(cb);
+            move |e| {
+                (*cb).emit((e, title.clone()));
+            }
+        }
+
+        let p = ctx.props();
+
+        let row_height = p.height / (p.rows.len().max(1) as f32);
+        let (lanes, lane_counts) = assign_lanes(&p.data, p.rows.len());
+
+        let rows = p.rows.iter().enumerate().map(|(i, label)| {
+            let y = p.y + i as f32 * row_height;
+            html! {
+                <>
+                <rect x={p.x.to_string()} y={y.to_string()} width={p.width.to_string()} height={row_height.to_string()} class="row" />
+                <text x={(p.x - p.row_label_offset).to_string()} y={(y + row_height * 0.5).to_string()}
+                    text-anchor="end" dominant-baseline="middle" class="row-label">{label}</text>
+                </>
+            }
+        });
+
+        let tasks = p.data.iter().zip(lanes).enumerate().filter_map(|(i, (task, lane))| {
+            let lane_count = *lane_counts.get(task.row)?;
+            let start = p.horizontal_scale.normalise(task.start).0.clamp(0.0, 1.0) * p.width;
+            let end = p.horizontal_scale.normalise(task.end).0.clamp(0.0, 1.0) * p.width;
+            if end <= start {
+                return None;
+            }
+
+            let lane_height = row_height / lane_count as f32;
+            let padding = lane_height * p.padding * 0.5;
+            let x = p.x + start;
+            let y = p.y + task.row as f32 * row_height + lane as f32 * lane_height + padding;
+            let width = end - start;
+            let height = lane_height - padding * 2.0;
+
+            let class = classes!("task", task.category.clone());
+            let onclick = p.onclick.reform(move |_: MouseEvent| i);
+            let title = p.tooltipper.as_ref().map(|tt| tt(task));
+
+            #[cfg(feature = "custom-tooltip")]
+            let bar = html! {
+                <rect x={x.to_string()} y={y.to_string()} width={width.to_string()} height={height.to_string()}
+                    class={class} onclick={onclick}
+                    onmouseover={onmouseover(&p.onmouseover, title.unwrap_or_default())} />
+            };
+            #[cfg(not(feature = "custom-tooltip"))]
+            let bar = html! {
+                <rect x={x.to_string()} y={y.to_string()} width={width.to_string()} height={height.to_string()}
+                    class={class} onclick={onclick}>
+                    if let Some(t) = title {
+                        <title>{t}</title>
+                    }
+                </rect>
+            };
+
+            Some(html! {
+                <>
+                {bar}
+                if let Some(l) = &task.label {
+                    <text x={(x + width * 0.5).to_string()} y={(y + height * 0.5).to_string()}
+                        text-anchor="middle" dominant-baseline="middle" pointer-events="none" class="label">{l}</text>
+                }
+                </>
+            })
+        });
+
+        html! {
+            <g class={classes!("timeline", &p.name)}>
+                { for rows }
+                { for tasks }
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(row: usize, start: i64, end: i64) -> Task {
+        Task {
+            row,
+            start,
+            end,
+            label: None,
+            category: None,
+        }
+    }
+
+    #[test]
+    fn test_assign_lanes() {
+        let data = vec![
+            task(0, 0, 10),
+            task(0, 5, 15),
+            task(0, 10, 20),
+            task(1, 0, 10),
+            task(0, 6, 8),
+        ];
+
+        assert_eq!(assign_lanes(&data, 3), (vec![0, 1, 0, 0, 2], vec![3, 1, 1]));
+    }
+
+    #[test]
+    fn test_assign_lanes_unknown_row() {
+        let data = vec![task(5, 0, 10)];
+
+        assert_eq!(assign_lanes(&data, 1), (vec![0], vec![1]));
+    }
+}
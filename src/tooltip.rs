@@ -0,0 +1,251 @@
+/// A Tooltip displays the tooltips of a chart when the custom-tooltip feature is enabled. The
+/// chart is provided as the component's children, and the tooltip is positioned next to the
+/// pointer while remaining inside of the chart's bounds. The tooltip is hidden when the pointer
+/// leaves the element that it was shown for.
+///
+/// Tooltips are shown via a [TooltipHandle] that is shared between the Tooltip component and
+/// the chart's components. The handle provides the [TooltipCallback] expected by a component's
+/// onmouseover property, and also the [HtmlTooltipCallback] expected by a component's ontooltip
+/// property so that tooltips may contain rich content. Tooltips may also be shown at a given
+/// position and hidden explicitly, as when following the cursor of another chart.
+///
+/// The following styling properties are available:
+///
+/// * tooltip-container - the element containing the chart and tooltip
+/// * tooltip - the tooltip
+use std::{cell::RefCell, rc::Rc};
+
+use gloo_events::EventListener;
+use web_sys::Element;
+use yew::prelude::*;
+
+use crate::series::{HtmlTooltipCallback, TooltipCallback};
+
+/// Positions a tooltip of a given size next to the pointer, within bounds of a width and height.
+/// The tooltip is placed below and to the right of the pointer, flipping to the other side when
+/// there is not enough room. Returns the top left of the tooltip.
+fn position(pointer: (f32, f32), size: (f32, f32), bounds: (f32, f32), offset: f32) -> (f32, f32) {
+    let place = |pointer: f32, size: f32, bound: f32| {
+        let after = pointer + offset;
+        let before = pointer - offset - size;
+        let start = if after + size <= bound || before < 0.0 {
+            after
+        } else {
+            before
+        };
+        start.min(bound - size).max(0.0)
+    };
+    (
+        place(pointer.0, size.0, bounds.0),
+        place(pointer.1, size.1, bounds.1),
+    )
+}
+
+/// Connects the chart components that produce tooltips with the Tooltip component that displays
+/// them. Handles are compared by reference.
+#[derive(Clone, Default)]
+pub struct TooltipHandle {
+    send: Rc<RefCell<Option<Callback<Msg>>>>,
+}
+
+impl TooltipHandle {
+    /// A callback that displays tooltip text, to be provided as an onmouseover property
+    pub fn callback(&self) -> Rc<TooltipCallback> {
+        let show = self.html_callback();
+        Rc::new(Callback::from(move |(e, text): (MouseEvent, String)| {
+            show.emit((e, html! {text}))
+        }))
+    }
+
+    /// A callback that displays tooltip Html
+    pub fn html_callback(&self) -> HtmlTooltipCallback {
+        let handle = self.clone();
+        Callback::from(move |(e, html): (MouseEvent, Html)| handle.send(Msg::Show(e, html)))
+    }
+
+    /// Displays tooltip Html next to a position in client coordinates, as given by a mouse
+    /// event's client_x and client_y. The tooltip remains until hidden or shown again.
+    pub fn show_at(&self, x: f32, y: f32, content: Html) {
+        self.send(Msg::ShowAt(x, y, content));
+    }
+
+    /// Hides any tooltip being displayed
+    pub fn hide(&self) {
+        self.send(Msg::Hide);
+    }
+
+    fn send(&self, msg: Msg) {
+        if let Some(cb) = self.send.borrow().as_ref() {
+            cb.emit(msg);
+        }
+    }
+}
+
+impl PartialEq for TooltipHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.send, &other.send)
+    }
+}
+
+pub enum Msg {
+    Show(MouseEvent, Html),
+    ShowAt(f32, f32, Html),
+    Hide,
+    Measured(f32, f32),
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct Props {
+    /// The chart
+    pub children: Html,
+    /// The handle through which tooltips are shown
+    pub handle: TooltipHandle,
+    /// A name to be used for CSS selection
+    #[prop_or_default]
+    pub name: AttrValue,
+    /// The distance of the tooltip from the pointer in pixels
+    #[prop_or(12.0)]
+    pub offset: f32,
+}
+
+pub struct Tooltip {
+    container: NodeRef,
+    content: Option<Html>,
+    pointer: (f32, f32),
+    size: (f32, f32),
+    tip: NodeRef,
+    _mouseout_listener: Option<EventListener>,
+}
+
+impl Tooltip {
+    fn register(ctx: &Context<Self>) {
+        *ctx.props().handle.send.borrow_mut() = Some(ctx.link().callback(|msg| msg));
+    }
+
+    // The position of a point in client coordinates relative to the container
+    fn pointer(&self, x: f32, y: f32) -> (f32, f32) {
+        match self.container.cast::<Element>() {
+            Some(container) => {
+                let rect = container.get_bounding_client_rect();
+                (x - rect.left() as f32, y - rect.top() as f32)
+            }
+            None => self.pointer,
+        }
+    }
+}
+
+impl Component for Tooltip {
+    type Message = Msg;
+
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self::register(ctx);
+        Tooltip {
+            container: NodeRef::default(),
+            content: None,
+            pointer: (0.0, 0.0),
+            size: (0.0, 0.0),
+            tip: NodeRef::default(),
+            _mouseout_listener: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Show(e, html) => {
+                self.pointer = self.pointer(e.client_x() as f32, e.client_y() as f32);
+                self._mouseout_listener = e.target().map(|target| {
+                    let hide = ctx.link().callback(|_| Msg::Hide);
+                    EventListener::once(&target, "mouseout", move |e| hide.emit(e.clone()))
+                });
+                self.content = Some(html);
+                true
+            }
+            Msg::ShowAt(x, y, html) => {
+                self.pointer = self.pointer(x, y);
+                self._mouseout_listener = None;
+                self.content = Some(html);
+                true
+            }
+            Msg::Hide => {
+                self._mouseout_listener = None;
+                self.content.take().is_some()
+            }
+            Msg::Measured(width, height) => {
+                let changed = self.size != (width, height);
+                self.size = (width, height);
+                changed
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().handle != old_props.handle {
+            *old_props.handle.send.borrow_mut() = None;
+            Self::register(ctx);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let p = ctx.props();
+
+        let tip = self.content.as_ref().map(|content| {
+            let bounds = self
+                .container
+                .cast::<Element>()
+                .map(|c| (c.client_width() as f32, c.client_height() as f32))
+                .unwrap_or_default();
+            let (left, top) = position(self.pointer, self.size, bounds, p.offset);
+            html! {
+                <div ref={self.tip.clone()} class="tooltip"
+                    style={format!("position: absolute; left: {}px; top: {}px; pointer-events: none", left, top)}>
+                    {content.clone()}
+                </div>
+            }
+        });
+
+        html! {
+            <div ref={self.container.clone()} class={classes!("tooltip-container", &p.name)} style="position: relative">
+                {p.children.clone()}
+                {for tip}
+            </div>
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        if let Some(tip) = self.tip.cast::<Element>() {
+            let rect = tip.get_bounding_client_rect();
+            ctx.link()
+                .send_message(Msg::Measured(rect.width() as f32, rect.height() as f32));
+        }
+    }
+
+    fn destroy(&mut self, ctx: &Context<Self>) {
+        *ctx.props().handle.send.borrow_mut() = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let bounds = (200.0, 100.0);
+
+        assert_eq!(
+            position((50.0, 20.0), (40.0, 20.0), bounds, 10.0),
+            (60.0, 30.0)
+        );
+        assert_eq!(
+            position((180.0, 90.0), (40.0, 20.0), bounds, 10.0),
+            (130.0, 60.0)
+        );
+        assert_eq!(
+            position((5.0, 5.0), (250.0, 20.0), bounds, 10.0),
+            (0.0, 15.0)
+        );
+    }
+}
@@ -0,0 +1,319 @@
+/// A VectorSeries represents data that has a direction and a magnitude at each x/y position,
+/// such as wind observations along a time axis. Each point is rendered as a glyph rotated to the
+/// point's direction: either an arrow, or a meteorological wind barb.
+///
+/// Directions are expressed in degrees, clockwise from north (12 o'clock), and follow the
+/// meteorological convention of describing where a wind is coming from. Arrows therefore point
+/// downwind, and the staffs of barbs point upwind. Barbs express their magnitude with a half
+/// barb for every 5 units, a full barb for every 10 units and a pennant for every 50 units,
+/// so magnitudes are expected to be in knots for conventional barbs.
+///
+/// A name is associated with the series to facilitate styling.
+///
+/// The following styling properties are available:
+///
+/// * series - the series as a whole
+/// * arrow - an arrow glyph
+/// * barb - a wind barb glyph
+/// * calm - the circle drawn for a wind barb with no magnitude
+use std::{marker::PhantomData, rc::Rc};
+
+use yew::prelude::*;
+
+#[cfg(feature = "custom-tooltip")]
+use crate::series::TooltipCallback;
+use crate::{axis::Scale, series::Scalar};
+
+/// Describes a vector series with each point having an x, y, direction and magnitude
+pub type Data<A, B> = Vec<(A, B, f32, f32)>;
+
+/// Describes a closure that takes data values (x, y, direction, magnitude) and produces tooltip
+/// strings for each datapoint.
+pub trait Tooltipper<A: Scalar, B: Scalar>: Fn(A, B, f32, f32) -> String {}
+
+impl<A: Scalar, B: Scalar, T: Fn(A, B, f32, f32) -> String> Tooltipper<A, B> for T {}
+
+/// Basic tooltip that just outputs the direction and magnitude
+pub fn vector_tooltip<A: Scalar, B: Scalar>() -> impl Tooltipper<A, B> {
+    |_, _, direction: f32, magnitude: f32| format!("{}° {}", direction as i32, magnitude as i32)
+}
+
+/// Describes how to render each item of series data
+#[derive(Clone, Copy, PartialEq)]
+pub enum Glyph {
+    /// An arrow centred on the point and pointing in the direction of flow
+    Arrow,
+    /// A meteorological wind barb with its staff starting at the point
+    Barb,
+}
+
+/// The number of pennants, full barbs and half barbs that represent a magnitude,
+/// given that the magnitude is rounded to the nearest 5.
+fn barb_counts(magnitude: f32) -> (u32, u32, u32) {
+    let rounded = ((magnitude / 5.0).round().max(0.0) as u32) * 5;
+    let pennants = rounded / 50;
+    let full = (rounded % 50) / 10;
+    let half = (rounded % 10) / 5;
+    (pennants, full, half)
+}
+
+// Draws an arrow pointing up and centred on the origin.
+fn arrow(len: f32) -> Html {
+    let half = len * 0.5;
+    let head = len * 0.25;
+    html! {
+        <>
+        <line x1="0" y1={half.to_string()} x2="0" y2={(-half).to_string()} />
+        <polyline points={format!("{},{} 0,{} {},{}", -head * 0.5, head - half, -half, head * 0.5, head - half)} fill="none" />
+        </>
+    }
+}
+
+// Draws a barb with its staff pointing up from the origin, and barbs on the right of the staff.
+fn barb(len: f32, magnitude: f32) -> Html {
+    let (pennants, full, half) = barb_counts(magnitude);
+    if pennants + full + half == 0 {
+        return html! {
+            <circle cx="0" cy="0" r={(len * 0.15).to_string()} fill="none" class="calm" />
+        };
+    }
+
+    let feather = len * 0.4;
+    let spacing = len * 0.12;
+    let dx = feather * 0.866;
+    let dy = feather * 0.5;
+
+    let mut y = -len;
+    let mut elements = Vec::with_capacity((pennants + full + half) as usize);
+    for _ in 0..pennants {
+        elements.push(html! {
+            <polygon points={format!("0,{} {},{} 0,{}", y, dx, y - dy, y + spacing)} />
+        });
+        y += spacing * 1.5;
+    }
+    for _ in 0..full {
+        elements.push(html! {
+            <line x1="0" y1={y.to_string()} x2={dx.to_string()} y2={(y - dy).to_string()} />
+        });
+        y += spacing;
+    }
+    if half > 0 {
+        // A lone half barb is set back from the end of the staff so it isn't mistaken for a full one.
+        if pennants + full == 0 {
+            y += spacing;
+        }
+        elements.push(html! {
+            <line x1="0" y1={y.to_string()} x2={(dx * 0.5).to_string()} y2={(y - dy * 0.5).to_string()} />
+        });
+    }
+
+    html! {
+        <>
+        <line x1="0" y1="0" x2="0" y2={(-len).to_string()} />
+        { elements }
+        </>
+    }
+}
+
+#[derive(Properties, Clone)]
+pub struct Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    /// A vector of data points that represents the series
+    pub data: Rc<Data<A, B>>,
+    /// The glyph to render at each point
+    pub glyph: Glyph,
+    /// The SVG length of each glyph, or the maximum length if a magnitude scale is provided
+    pub glyph_len: f32,
+    /// The SVG height of the series
+    pub height: f32,
+    /// The scaling factor for data along the x axis
+    pub horizontal_scale: Rc<dyn Scale<Scalar = A>>,
+    /// An optional scale to vary the length of each glyph with its magnitude
+    #[prop_or_default]
+    pub magnitude_scale: Option<Rc<dyn Scale<Scalar = f32>>>,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    #[cfg(feature = "custom-tooltip")]
+    /// A callback to receive mouseover events along with tooltipper function text results. Requires
+    /// the custom-tooltip feature.
+    #[prop_or_else(|| Rc::new(Callback::noop()))]
+    pub onmouseover: Rc<TooltipCallback>,
+    /// An optional function that renders a string to be used for tooltips
+    #[prop_or_default]
+    pub tooltipper: Option<Rc<dyn Tooltipper<A, B>>>,
+    /// The scaling factor for data along the y axis
+    pub vertical_scale: Rc<dyn Scale<Scalar = B>>,
+    /// The SVG width of the series
+    pub width: f32,
+    /// The start position
+    pub x: f32,
+    /// The start position
+    pub y: f32,
+}
+
+impl<A, B> Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    #[cfg(feature = "custom-tooltip")]
+    fn is_onmouseover_eq(&self, other: &Self) -> bool {
+        self.onmouseover == other.onmouseover
+    }
+    #[cfg(not(feature = "custom-tooltip"))]
+    fn is_onmouseover_eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<A, B> PartialEq for Props<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.data, &other.data)
+            && self.glyph == other.glyph
+            && self.glyph_len == other.glyph_len
+            && self.height == other.height
+            && self.name == other.name
+            && self.is_onmouseover_eq(other)
+            && match (self.magnitude_scale.as_ref(), other.magnitude_scale.as_ref()) {
+                (Some(left), Some(right)) => std::ptr::eq(&**left as *const _ as *const u8, &**right as *const _ as *const u8),
+                (None, None) => true,
+                _ => false
+            }
+            && match (self.tooltipper.as_ref(), other.tooltipper.as_ref()) {
+                (Some(left), Some(right)) => std::ptr::eq(left as *const _ as *const u8, right as *const _ as *const u8),
+                _=> false
+            }
+            && self.width == other.width
+            && self.x == other.x
+            && self.y == other.y
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.horizontal_scale as *const _ as *const u8,
+                &*other.horizontal_scale as *const _ as *const u8,
+            )
+            && std::ptr::eq(
+                &*self.vertical_scale as *const _ as *const u8,
+                &*other.vertical_scale as *const _ as *const u8,
+            )
+    }
+}
+
+pub struct VectorSeries<A, B> {
+    phantom: PhantomData<(A, B)>,
+}
+
+impl<A: 'static, B: 'static> Component for VectorSeries<A, B>
+where
+    A: Scalar,
+    B: Scalar,
+{
+    type Message = ();
+
+    type Properties = Props<A, B>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        VectorSeries {
+            phantom: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        #[cfg(feature = "custom-tooltip")]
+        fn onmouseover(cb: &Rc<TooltipCallback>, title: String) -> impl Fn(MouseEvent) {
+            let cb = Rc::clone(cb);
+            move |e| {
+                (*cb).emit((e, title.clone()));
+            }
+        }
+
+        let p = ctx.props();
+
+        let (glyph_class, rotation_offset) = match p.glyph {
+            Glyph::Arrow => ("arrow", 180.0),
+            Glyph::Barb => ("barb", 0.0),
+        };
+
+        let x_bounds = -0.1..=p.width + 0.1;
+        let y_bounds = -0.1..=p.height + 0.1;
+
+        let glyphs = p
+            .data
+            .iter()
+            .filter_map(|(data_x, data_y, direction, magnitude)| {
+                let x = p.horizontal_scale.normalise(*data_x).0 * p.width;
+                let y = p.vertical_scale.normalise(*data_y).0 * p.height;
+                if !(x_bounds.contains(&x) && y_bounds.contains(&y)) {
+                    return None;
+                }
+                let x = x + p.x;
+                let y = p.height - y + p.y;
+
+                let len = p
+                    .magnitude_scale
+                    .as_ref()
+                    .map(|s| s.normalise(*magnitude).0.clamp(0.0, 1.0) * p.glyph_len)
+                    .unwrap_or(p.glyph_len);
+
+                let glyph = match p.glyph {
+                    Glyph::Arrow => arrow(len),
+                    Glyph::Barb => barb(len, *magnitude),
+                };
+
+                let transform =
+                    format!("translate({x},{y}) rotate({})", direction + rotation_offset);
+                let title = p
+                    .tooltipper
+                    .as_ref()
+                    .map(|tt| tt(*data_x, *data_y, *direction, *magnitude));
+
+                #[cfg(feature = "custom-tooltip")]
+                let html = html! {
+                    <g transform={transform} class={glyph_class}
+                        onmouseover={onmouseover(&p.onmouseover, title.unwrap_or_default())}>
+                        {glyph}
+                    </g>
+                };
+                #[cfg(not(feature = "custom-tooltip"))]
+                let html = html! {
+                    <g transform={transform} class={glyph_class}>
+                        if let Some(t) = title {
+                            <title>{t}</title>
+                        }
+                        {glyph}
+                    </g>
+                };
+
+                Some(html)
+            });
+
+        html! {
+            <g class={classes!("series", &p.name)}>
+                { for glyphs }
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_barb_counts() {
+        assert_eq!(barb_counts(0.0), (0, 0, 0));
+        assert_eq!(barb_counts(2.0), (0, 0, 0));
+        assert_eq!(barb_counts(5.0), (0, 0, 1));
+        assert_eq!(barb_counts(15.0), (0, 1, 1));
+        assert_eq!(barb_counts(23.0), (0, 2, 1));
+        assert_eq!(barb_counts(65.0), (1, 1, 1));
+        assert_eq!(barb_counts(100.0), (2, 0, 0));
+    }
+}
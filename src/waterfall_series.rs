@@ -0,0 +1,277 @@
+/// A WaterfallSeries represents a running total as it is increased and decreased by a sequence
+/// of signed deltas. Each delta is drawn as a bar floating between the running total before and
+/// after it, and subtotals may be placed within the sequence to draw the running total as a bar
+/// from zero. Connector lines join the end of each bar to the start of the next.
+///
+/// A name is associated with the series to facilitate styling.
+///
+/// The following styling properties are available:
+///
+/// * series - the series as a whole
+/// * increase - a bar for a positive delta
+/// * decrease - a bar for a negative delta
+/// * total - a bar for a subtotal
+/// * connector - the line joining one bar with the next
+use std::{marker::PhantomData, rc::Rc};
+
+use yew::prelude::*;
+
+#[cfg(feature = "custom-tooltip")]
+use crate::series::TooltipCallback;
+use crate::{axis::Scale, series::Scalar};
+
+/// Describes each item of waterfall data
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Step {
+    /// Changes the running total by a signed amount
+    Delta(f32),
+    /// Marks the running total at this point as a subtotal
+    Total,
+}
+
+/// Describes a waterfall series with each step being positioned at some x value
+pub type Data<A> = Vec<(A, Step)>;
+
+/// Describes a closure that takes data values (x, step value, running total) and produces tooltip
+/// strings for each step. The step value is the delta for a delta, or the running total for
+/// a subtotal.
+pub trait Tooltipper<A: Scalar>: Fn(A, f32, f32) -> String {}
+
+impl<A: Scalar, T: Fn(A, f32, f32) -> String> Tooltipper<A> for T {}
+
+/// Basic tooltip that just outputs the step value
+pub fn step_tooltip<A: Scalar>() -> impl Tooltipper<A> {
+    |_, value: f32, _| (value as i32).to_string()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    Increase,
+    Decrease,
+    Total,
+}
+
+impl Kind {
+    fn class(&self) -> &'static str {
+        match self {
+            Kind::Increase => "increase",
+            Kind::Decrease => "decrease",
+            Kind::Total => "total",
+        }
+    }
+}
+
+/// Computes the value at which each bar starts and ends along with its kind.
+fn running_totals<A>(data: &Data<A>) -> Vec<(f32, f32, Kind)> {
+    let mut total = 0.0;
+    data.iter()
+        .map(|(_, step)| match step {
+            Step::Delta(delta) => {
+                let from = total;
+                total += delta;
+                let kind = if *delta < 0.0 {
+                    Kind::Decrease
+                } else {
+                    Kind::Increase
+                };
+                (from, total, kind)
+            }
+            Step::Total => (0.0, total, Kind::Total),
+        })
+        .collect()
+}
+
+#[derive(Properties, Clone)]
+pub struct Props<A>
+where
+    A: Scalar,
+{
+    /// The SVG width of each bar
+    pub bar_width: f32,
+    /// A vector of steps that represents the series
+    pub data: Rc<Data<A>>,
+    /// The SVG height of the series
+    pub height: f32,
+    /// The scaling factor for data along the x axis
+    pub horizontal_scale: Rc<dyn Scale<Scalar = A>>,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    #[cfg(feature = "custom-tooltip")]
+    /// A callback to receive mouseover events along with tooltipper function text results. Requires
+    /// the custom-tooltip feature.
+    #[prop_or_else(|| Rc::new(Callback::noop()))]
+    pub onmouseover: Rc<TooltipCallback>,
+    /// An optional function that renders a string to be used for tooltips
+    #[prop_or_default]
+    pub tooltipper: Option<Rc<dyn Tooltipper<A>>>,
+    /// The scaling factor for data along the y axis
+    pub vertical_scale: Rc<dyn Scale<Scalar = f32>>,
+    /// The SVG width of the series
+    pub width: f32,
+    /// The start position
+    pub x: f32,
+    /// The start position
+    pub y: f32,
+}
+
+impl<A> Props<A>
+where
+    A: Scalar,
+{
+    #[cfg(feature = "custom-tooltip")]
+    fn is_onmouseover_eq(&self, other: &Self) -> bool {
+        self.onmouseover == other.onmouseover
+    }
+    #[cfg(not(feature = "custom-tooltip"))]
+    fn is_onmouseover_eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<A> PartialEq for Props<A>
+where
+    A: Scalar,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.bar_width == other.bar_width
+            && Rc::ptr_eq(&self.data, &other.data)
+            && self.height == other.height
+            && self.name == other.name
+            && self.is_onmouseover_eq(other)
+            && match (self.tooltipper.as_ref(), other.tooltipper.as_ref()) {
+                (Some(left), Some(right)) => std::ptr::eq(left as *const _ as *const u8, right as *const _ as *const u8),
+                _=> false
+            }
+            && self.width == other.width
+            && self.x == other.x
+            && self.y == other.y
+            // test reference equality, avoiding issues with vtables discussed in
+            // https://github.com/rust-lang/rust/issues/46139
+            && std::ptr::eq(
+                &*self.horizontal_scale as *const _ as *const u8,
+                &*other.horizontal_scale as *const _ as *const u8,
+            )
+            && std::ptr::eq(
+                &*self.vertical_scale as *const _ as *const u8,
+                &*other.vertical_scale as *const _ as *const u8,
+            )
+    }
+}
+
+pub struct WaterfallSeries<A> {
+    phantom: PhantomData<A>,
+}
+
+impl<A: 'static> Component for WaterfallSeries<A>
+where
+    A: Scalar,
+{
+    type Message = ();
+
+    type Properties = Props<A>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        WaterfallSeries {
+            phantom: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        #[cfg(feature = "custom-tooltip")]
+        fn onmouseover(cb: &Rc<TooltipCallback>, title: String) -> impl Fn(MouseEvent) {
+            let cb = Rc::clone(cb);
+            move |e| {
+                (*cb).emit((e, title.clone()));
+            }
+        }
+
+        let p = ctx.props();
+
+        let to_y = |value: f32| p.height - p.vertical_scale.normalise(value).0 * p.height + p.y;
+        let half_width = p.bar_width * 0.5;
+        let x_bounds = -0.1..=p.width + 0.1;
+
+        let mut svg_elements = Vec::with_capacity(p.data.len() * 2);
+        let mut last_end: Option<(f32, f32)> = None;
+
+        for ((data_x, step), (from, to, kind)) in p.data.iter().zip(running_totals(&p.data)) {
+            let x = p.horizontal_scale.normalise(*data_x).0 * p.width;
+            if !x_bounds.contains(&x) {
+                last_end = None;
+                continue;
+            }
+            let x = x + p.x;
+            let (y1, y2) = (to_y(from), to_y(to));
+
+            if let Some((last_x, last_y)) = last_end {
+                svg_elements.push(html! {
+                    <line x1={last_x.to_string()} y1={last_y.to_string()} x2={(x - half_width).to_string()} y2={last_y.to_string()} class="connector" />
+                });
+            }
+
+            let title = p.tooltipper.as_ref().map(|tt| {
+                let value = match step {
+                    Step::Delta(delta) => *delta,
+                    Step::Total => to,
+                };
+                tt(*data_x, value, to)
+            });
+            let (rect_x, rect_y) = (x - half_width, y1.min(y2));
+            let rect_height = (y1 - y2).abs();
+
+            #[cfg(feature = "custom-tooltip")]
+            let html = html! {
+                <rect x={rect_x.to_string()} y={rect_y.to_string()} width={p.bar_width.to_string()} height={rect_height.to_string()}
+                    class={kind.class()}
+                    onmouseover={onmouseover(&p.onmouseover, title.unwrap_or_default())} />
+            };
+            #[cfg(not(feature = "custom-tooltip"))]
+            let html = html! {
+                <rect x={rect_x.to_string()} y={rect_y.to_string()} width={p.bar_width.to_string()} height={rect_height.to_string()}
+                    class={kind.class()}>
+                    if let Some(t) = title {
+                        <title>{t}</title>
+                    }
+                </rect>
+            };
+            svg_elements.push(html);
+
+            last_end = Some((x + half_width, y2));
+        }
+
+        html! {
+            <g class={classes!("series", &p.name)}>
+                { svg_elements }
+            </g>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_running_totals() {
+        let data: Data<f32> = vec![
+            (0.0, Step::Delta(10.0)),
+            (1.0, Step::Delta(5.0)),
+            (2.0, Step::Delta(-8.0)),
+            (3.0, Step::Total),
+            (4.0, Step::Delta(-2.0)),
+            (5.0, Step::Total),
+        ];
+
+        assert_eq!(
+            running_totals(&data),
+            vec![
+                (0.0, 10.0, Kind::Increase),
+                (10.0, 15.0, Kind::Increase),
+                (15.0, 7.0, Kind::Decrease),
+                (0.0, 7.0, Kind::Total),
+                (7.0, 5.0, Kind::Decrease),
+                (0.0, 5.0, Kind::Total),
+            ]
+        );
+    }
+}
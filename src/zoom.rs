@@ -0,0 +1,339 @@
+/// A ZoomPan is an interaction overlay that zooms and pans the x domain of a chart. The mouse
+/// wheel zooms around the pointer, dragging pans, and a two-finger pinch zooms and pans at once.
+/// The domain is kept within an extent, and may be given a minimum span so that zooming in is
+/// limited.
+///
+/// The component does not own the domain. Instead, each change is reported through a callback
+/// so that the scales of the chart's [Series](crate::series::Series) and
+/// [Axis](crate::axis::Axis) components may be recreated with the new domain, which is then
+/// provided back to this component.
+///
+/// The overlay is drawn as a transparent rectangle over the plot area and so it should be declared
+/// after any series so that it receives pointer events.
+///
+/// The component takes a "name" property field so that it may be easily referenced when styled.
+///
+/// The following styling properties are available:
+///
+/// * zoom-pan - the overlay
+use std::{marker::PhantomData, ops::Range};
+
+use gloo_events::{EventListener, EventListenerOptions};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, WheelEvent};
+use yew::prelude::*;
+
+use crate::series::Scalar;
+
+const WHEEL_SENSITIVITY: f64 = 0.002;
+const WHEEL_LINE_HEIGHT: f64 = 16.0;
+
+/// The ZoomScalar trait expresses scalars that may be zoomed and panned by converting them to
+/// and from f64 values
+pub trait ZoomScalar: Scalar {
+    fn to_f64(self) -> f64;
+    fn from_f64(value: f64) -> Self;
+}
+
+impl ZoomScalar for f32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+impl ZoomScalar for i64 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value.round() as i64
+    }
+}
+
+/// Scales a domain by a factor while keeping the value at a fraction of it in place.
+fn zoom(domain: (f64, f64), anchor: f64, factor: f64) -> (f64, f64) {
+    let span = domain.1 - domain.0;
+    let value = domain.0 + anchor * span;
+    let span = span * factor;
+    let start = value - anchor * span;
+    (start, start + span)
+}
+
+/// Shifts a domain by a fraction of its span.
+fn pan(domain: (f64, f64), delta: f64) -> (f64, f64) {
+    let shift = (domain.1 - domain.0) * delta;
+    (domain.0 + shift, domain.1 + shift)
+}
+
+/// Determines the domain that moves the values at two fractions of a domain to two other
+/// fractions. Returns None if either pair of fractions coincide.
+fn pinch(domain: (f64, f64), from: (f64, f64), to: (f64, f64)) -> Option<(f64, f64)> {
+    if from.0 == from.1 || to.0 == to.1 {
+        return None;
+    }
+    let span = domain.1 - domain.0;
+    let (a, b) = (domain.0 + from.0 * span, domain.0 + from.1 * span);
+    let span = (b - a) / (to.1 - to.0);
+    let start = a - to.0 * span;
+    Some((start, start + span))
+}
+
+/// Constrains a domain to lie within an extent with a span of at least a minimum. When the span
+/// changes, the value at the anchor fraction of the domain is kept in place where possible.
+pub(crate) fn clamp(
+    domain: (f64, f64),
+    extent: (f64, f64),
+    min_span: f64,
+    anchor: f64,
+) -> (f64, f64) {
+    let max_span = extent.1 - extent.0;
+    let value = domain.0 + anchor * (domain.1 - domain.0);
+    let span = (domain.1 - domain.0).max(min_span).min(max_span);
+    let start = (value - anchor * span).min(extent.1 - span).max(extent.0);
+    (start, start + span)
+}
+
+// The domain and pointer positions at the start of a drag or pinch
+struct Gesture {
+    domain: (f64, f64),
+    pointers: Vec<(i32, f64)>,
+}
+
+pub enum Msg {
+    Wheel(f64, f64),
+    Down(i32, f64),
+    Move(i32, f64),
+    Up(i32),
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct Props<S>
+where
+    S: ZoomScalar,
+{
+    /// The current x domain
+    pub domain: Range<S>,
+    /// The x domain that zooming and panning is constrained to
+    pub extent: Range<S>,
+    /// The SVG height of the plot area
+    pub height: f32,
+    /// The minimum span of the domain. If None then zooming in is not limited.
+    #[prop_or_default]
+    pub min_span: Option<S>,
+    /// A name to be used for CSS selection
+    pub name: AttrValue,
+    /// A callback to receive the new domain as it changes
+    pub ondomainchange: Callback<Range<S>>,
+    /// The SVG width of the plot area
+    pub width: f32,
+    /// The start position
+    pub x: f32,
+    /// The start position
+    pub y: f32,
+}
+
+pub struct ZoomPan<S> {
+    gesture: Option<Gesture>,
+    overlay: NodeRef,
+    pointers: Vec<(i32, f64)>,
+    _wheel_listener: Option<EventListener>,
+    phantom: PhantomData<S>,
+}
+
+impl<S> ZoomPan<S>
+where
+    S: ZoomScalar,
+{
+    fn domain(p: &Props<S>) -> (f64, f64) {
+        (p.domain.start.to_f64(), p.domain.end.to_f64())
+    }
+
+    fn emit(p: &Props<S>, domain: (f64, f64), anchor: f64) {
+        let extent = (p.extent.start.to_f64(), p.extent.end.to_f64());
+        let min_span = p.min_span.map(|s| s.to_f64()).unwrap_or_default();
+        let (start, end) = clamp(domain, extent, min_span, anchor);
+        let domain = S::from_f64(start)..S::from_f64(end);
+        if domain != p.domain {
+            p.ondomainchange.emit(domain);
+        }
+    }
+
+    // Starts a new gesture from the current domain whenever the pointers change.
+    fn restart_gesture(&mut self, p: &Props<S>) {
+        self.gesture = (!self.pointers.is_empty()).then(|| Gesture {
+            domain: Self::domain(p),
+            pointers: self.pointers.clone(),
+        });
+    }
+}
+
+// The fraction of an element's width at which a pointer is positioned
+fn fraction(e: &MouseEvent) -> f64 {
+    let rect = e
+        .target_unchecked_into::<Element>()
+        .get_bounding_client_rect();
+    if rect.width() > 0.0 {
+        (e.client_x() as f64 - rect.left()) / rect.width()
+    } else {
+        0.0
+    }
+}
+
+impl<S: 'static> Component for ZoomPan<S>
+where
+    S: ZoomScalar,
+{
+    type Message = Msg;
+
+    type Properties = Props<S>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        ZoomPan {
+            gesture: None,
+            overlay: NodeRef::default(),
+            pointers: vec![],
+            _wheel_listener: None,
+            phantom: PhantomData,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let p = ctx.props();
+        match msg {
+            Msg::Wheel(anchor, delta) => {
+                let factor = (delta * WHEEL_SENSITIVITY).exp2();
+                Self::emit(p, zoom(Self::domain(p), anchor, factor), anchor);
+            }
+            Msg::Down(id, fraction) => {
+                self.pointers.retain(|(i, _)| *i != id);
+                self.pointers.push((id, fraction));
+                self.restart_gesture(p);
+            }
+            Msg::Move(id, fraction) => {
+                if let Some(pointer) = self.pointers.iter_mut().find(|(i, _)| *i == id) {
+                    pointer.1 = fraction;
+                }
+                if let Some(Gesture { domain, pointers }) = &self.gesture {
+                    let domain = match (pointers.as_slice(), self.pointers.as_slice()) {
+                        ([(_, from)], [(_, to)]) => Some((pan(*domain, from - to), 0.5)),
+                        ([(_, from0), (_, from1), ..], [(_, to0), (_, to1), ..]) => {
+                            pinch(*domain, (*from0, *from1), (*to0, *to1))
+                                .map(|domain| (domain, (to0 + to1) * 0.5))
+                        }
+                        _ => None,
+                    };
+                    if let Some((domain, anchor)) = domain {
+                        Self::emit(p, domain, anchor);
+                    }
+                }
+            }
+            Msg::Up(id) => {
+                self.pointers.retain(|(i, _)| *i != id);
+                self.restart_gesture(p);
+            }
+        }
+        false
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let p = ctx.props();
+
+        let onpointerdown = ctx.link().callback(|e: PointerEvent| {
+            let _ = e
+                .target_unchecked_into::<Element>()
+                .set_pointer_capture(e.pointer_id());
+            Msg::Down(e.pointer_id(), fraction(&e))
+        });
+        let onpointermove = ctx
+            .link()
+            .callback(|e: PointerEvent| Msg::Move(e.pointer_id(), fraction(&e)));
+        let onpointerup = ctx
+            .link()
+            .callback(|e: PointerEvent| Msg::Up(e.pointer_id()));
+        let onpointercancel = ctx
+            .link()
+            .callback(|e: PointerEvent| Msg::Up(e.pointer_id()));
+
+        html! {
+            <rect ref={self.overlay.clone()} x={p.x.to_string()} y={p.y.to_string()} width={p.width.to_string()} height={p.height.to_string()}
+                fill="transparent" style="touch-action: none" class={classes!("zoom-pan", &p.name)}
+                onpointerdown={onpointerdown} onpointermove={onpointermove}
+                onpointerup={onpointerup} onpointercancel={onpointercancel} />
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            if let Some(overlay) = self.overlay.cast::<Element>() {
+                // Wheel events are listened to directly so that scrolling the page may be prevented
+                let on_wheel = ctx
+                    .link()
+                    .callback(|(anchor, delta)| Msg::Wheel(anchor, delta));
+                self._wheel_listener = Some(EventListener::new_with_options(
+                    &overlay,
+                    "wheel",
+                    EventListenerOptions::enable_prevent_default(),
+                    move |e| {
+                        if let Some(e) = e.dyn_ref::<WheelEvent>() {
+                            e.prevent_default();
+                            let delta = match e.delta_mode() {
+                                WheelEvent::DOM_DELTA_LINE => e.delta_y() * WHEEL_LINE_HEIGHT,
+                                _ => e.delta_y(),
+                            };
+                            on_wheel.emit((fraction(e), delta));
+                        }
+                    },
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zoom() {
+        assert_eq!(zoom((0.0, 100.0), 0.5, 0.5), (25.0, 75.0));
+        assert_eq!(zoom((0.0, 100.0), 0.0, 2.0), (0.0, 200.0));
+        assert_eq!(zoom((0.0, 100.0), 1.0, 0.5), (50.0, 100.0));
+    }
+
+    #[test]
+    fn test_pan() {
+        assert_eq!(pan((0.0, 100.0), 0.25), (25.0, 125.0));
+        assert_eq!(pan((0.0, 100.0), -0.5), (-50.0, 50.0));
+    }
+
+    #[test]
+    fn test_pinch() {
+        assert_eq!(
+            pinch((0.0, 100.0), (0.25, 0.75), (0.0, 1.0)),
+            Some((25.0, 75.0))
+        );
+        assert_eq!(
+            pinch((0.0, 100.0), (0.25, 0.75), (0.5, 1.0)),
+            Some((-25.0, 75.0))
+        );
+        assert_eq!(pinch((0.0, 100.0), (0.5, 0.5), (0.0, 1.0)), None);
+    }
+
+    #[test]
+    fn test_clamp() {
+        let extent = (0.0, 100.0);
+
+        assert_eq!(clamp((10.0, 20.0), extent, 0.0, 0.5), (10.0, 20.0));
+        assert_eq!(clamp((-10.0, 20.0), extent, 0.0, 0.5), (0.0, 30.0));
+        assert_eq!(clamp((90.0, 120.0), extent, 0.0, 0.5), (70.0, 100.0));
+        assert_eq!(clamp((-50.0, 150.0), extent, 0.0, 0.5), (0.0, 100.0));
+        assert_eq!(clamp((50.0, 51.0), extent, 10.0, 0.5), (45.5, 55.5));
+        assert_eq!(clamp((50.0, 51.0), extent, 10.0, 0.25), (47.75, 57.75));
+        assert_eq!(clamp((98.0, 99.0), extent, 10.0, 0.75), (90.0, 100.0));
+    }
+}